@@ -0,0 +1,63 @@
+//! Compares writing sequences through [`SGRBuilder::write_to`] against
+//! formatting every code into its own `String` through `u8::to_string`, as was done before
+//!
+//! Run with `cargo bench`
+use std::{
+    hint::black_box,
+    time::{Duration, Instant},
+};
+
+use easy_sgr::{
+    CapableBuilder, CapableWriter, Color::*, EasySGR, FmtWriter, Param, SGRBuilder, SGRWriter,
+    Style::*,
+};
+
+const ITERATIONS: u32 = 200_000;
+
+fn main() {
+    let sgr = Bold
+        .style(Italic)
+        .style(Underline)
+        .color(RgbFg(255, 128, 0))
+        .color(ByteBg(236));
+    let mut builder = SGRBuilder::default();
+    sgr.place_all(&mut builder);
+    let params = builder.0;
+    // the previous builder only held codes, extended colors being written as several
+    let codes: Vec<u8> = params
+        .iter()
+        .flat_map(|param| match *param {
+            Param::Code(code) => vec![code],
+            Param::Byte(code, n) => vec![code, 5, n],
+            Param::Rgb(code, r, g, b) => vec![code, 2, r, g, b],
+            Param::Sub(..) => unreachable!("sub-parameters were not written before"),
+        })
+        .collect();
+
+    let allocating = bench(|writer| {
+        writer.write("\x1b[")?;
+        writer.write(&codes[0].to_string())?;
+        for code in &codes[1..] {
+            writer.write(";")?;
+            writer.write(&code.to_string())?;
+        }
+        writer.write("m")
+    });
+    let mut builder = SGRBuilder(params.clone());
+    let buffered = bench(|writer| builder.write_to(writer));
+
+    println!("allocating: {:>8.1?} per sequence", allocating / ITERATIONS);
+    println!("buffered:   {:>8.1?} per sequence", buffered / ITERATIONS);
+}
+
+/// Times writing a sequence `ITERATIONS` times into a reused [`String`]
+fn bench(mut write: impl FnMut(&mut SGRWriter<FmtWriter<String>>) -> std::fmt::Result) -> Duration {
+    let mut writer = SGRWriter::from(String::with_capacity(64));
+    let start = Instant::now();
+    for _ in 0..ITERATIONS {
+        writer.writer.0.clear();
+        write(&mut writer).unwrap();
+        black_box(&writer.writer.0);
+    }
+    start.elapsed()
+}
@@ -277,10 +277,7 @@ fn build_args<const MERGE_CURLY: bool>(
     input: TokenStream,
 ) -> Result<TokenStream, TokenStream> {
     let tokens = input.into_iter();
-    let stream = match StreamParts::from_parts(kind, tokens) {
-        Ok(stream) => stream,
-        Err(tokens) => return Err(tokens),
-    };
+    let stream = StreamParts::from_parts(kind, tokens)?;
 
     let (literal, parsed_literal) = match &stream.kind {
         StreamKind::Standard(literal) | StreamKind::Writer(_, Some((_, literal))) => (
@@ -438,7 +435,7 @@ impl StreamKind {
                 }
                 let mut writer = Vec::new();
                 let Some(punct) = find_punct(&mut writer, tokens) else {
-                    return Ok(Writer(writer, None))
+                    return Ok(Writer(writer, None));
                 };
                 match tokens.next() {
                     Some(TokenTree::Literal(literal)) => Ok(Writer(writer, Some((punct, literal)))),
@@ -10,13 +10,13 @@ pub enum UnwrappedLiteral<'a> {
 }
 /// [Unwraps](UnwrappedLiteral) string, returning `None`
 /// when string is invalid
-pub fn unwrap_string(s: &str) -> Option<UnwrappedLiteral> {
+pub fn unwrap_string(s: &str) -> Option<UnwrappedLiteral<'_>> {
     use UnwrappedLiteral::*;
     match s.strip_prefix('r') {
         Some(s) => {
-            let len = s.as_bytes().len();
+            let len = s.len();
             let s = s.trim_matches('#');
-            let diff = len - s.as_bytes().len();
+            let diff = len - s.len();
             if diff % 2 == 0 {
                 s.strip_prefix('"')?
                     .strip_suffix('"')
@@ -64,7 +64,7 @@ impl From<ParseIntError> for Error {
 /// - Unclosed bracket
 /// - Invalid keyword
 ///
-/// Invalid strings can also be occasionally returned with an Ok(), in
+/// Invalid strings can also be occasionally returned with an `Ok()`, in
 /// these cases the string will continue being parsed> When returned the
 /// compiler is expected to deal with the error.
 /// The spots where these cases occur be annotated by the comment:
@@ -112,7 +112,7 @@ where
 /// # Returns
 ///
 /// - `Ok(Some(_))` when parsing has succeeded, returned value is
-/// meant to be used as the next `char` for parsing
+///   meant to be used as the next `char` for parsing
 /// - Ok(None) when parsing has succeeded, parsing to continue as normal
 /// - Err(Error) when an unrecoverable invalid string has been detected
 fn parse_escape(
@@ -160,7 +160,7 @@ fn parse_escape(
 /// - `next_char`: the index, char pair after the opening brace
 /// - `s`: the full string to parse
 /// - `chars`: the string's `char_indices`,
-/// with `chars.next()` being the char after `next_char`
+///   with `chars.next()` being the char after `next_char`
 /// - `buf`: the string buf to append and return
 /// - `check_curly`: fn to check if char is curly
 ///
@@ -176,7 +176,7 @@ fn parse_param(
 ) -> Result<(), Error> {
     let Some((start, ch)) = next_char else {
         // INVALID HERE
-        buf.push( '{');
+        buf.push('{');
         return Ok(());
     };
     if let Some(s) = check_curly(ch) {
@@ -186,7 +186,7 @@ fn parse_param(
 
     // INVALID RETURN
     let Some(end) = chars.find(|ch| ch.1 == '}') else {
-        buf.push_str( &s[start-1..]);
+        buf.push_str(&s[start - 1..]);
         return Ok(());
     };
     let end = end.0;
@@ -228,11 +228,15 @@ fn parse_24bit(chars: &mut CharIndices, s: &str) -> Option<char> {
 ///
 /// - `Err(ParseError)` if `s` is an invalid keyword
 ///
-/// First [`parse_common`] is used, if it fails [`complex_color`] is used
+/// First [`parse_common`] is used, then [`parse_extended`],
+/// if both fail [`complex_color`] is used
 fn parse_sgr(s: &str, buf: &mut String) -> Result<(), Error> {
     if let Some(n) = parse_common(s) {
         n.append_to(buf);
         Ok(())
+    } else if let Some(codes) = parse_extended(s) {
+        buf.push_str(codes);
+        Ok(())
     } else {
         complex_color(s, buf)
     }
@@ -278,22 +282,54 @@ fn parse_common(s: &str) -> Option<u8> {
         "on-cyan" => Some(46),
         "on-white" => Some(47),
         "on-default" => Some(49),
+        // bright foregrounds
+        "bright-black" => Some(90),
+        "bright-red" => Some(91),
+        "bright-green" => Some(92),
+        "bright-yellow" => Some(93),
+        "bright-blue" => Some(94),
+        "bright-magenta" => Some(95),
+        "bright-cyan" => Some(96),
+        "bright-white" => Some(97),
+        // bright backgrounds
+        "on-bright-black" => Some(100),
+        "on-bright-red" => Some(101),
+        "on-bright-green" => Some(102),
+        "on-bright-yellow" => Some(103),
+        "on-bright-blue" => Some(104),
+        "on-bright-magenta" => Some(105),
+        "on-bright-cyan" => Some(106),
+        "on-bright-white" => Some(107),
+        _ => None,
+    }
+}
+/// Parses keywords that are not written as a single code
+fn parse_extended(s: &str) -> Option<&'static str> {
+    match s {
+        // underline styles
+        "double-underline" => Some("4:2"),
+        "curly-underline" => Some("4:3"),
+        "dotted-underline" => Some("4:4"),
+        "dashed-underline" => Some("4:5"),
+        // underline colors
+        "ul-default" => Some("59"),
         _ => None,
     }
 }
 /// Parses more complex color configurations.
 ///
 /// Colors are expected to be one of the following,
-/// optionally prefixed by `on-` to indicate being a background color:
+/// optionally prefixed by `on-` to indicate being a background color,
+/// or `ul-` to indicate being an underline color:
 ///
-/// - `u8` -> `(38|48);5;u8`
-/// - `u8,u8,u8` -> `(38|48);2;u8;u8;u8`
+/// - `u8` -> `(38|48|58);5;u8`
+/// - `u8,u8,u8` -> `(38|48|58);2;u8;u8;u8`
 ///
 /// And, prefixed with `#` to indicate hex,
 /// but without any commas:
 ///
-/// - `#u8` -> `(38|48);5;u8`
-/// - `#u8u8u8` -> `(38|48);2;u8;u8;u8`
+/// - `#u8` -> `(38|48|58);5;u8`
+/// - `#u8u8u8` -> `(38|48|58);2;u8;u8;u8`
 ///
 /// so some example colors could be
 ///
@@ -301,8 +337,13 @@ fn parse_common(s: &str) -> Option<u8> {
 /// - `15,115,215` -> 38;2;15;115;215
 /// - `#0f` -> 38;5;15
 /// - `on-#0f73d7` -> 48;2;15;115;215
+/// - `ul-208` -> 58;5;208
 fn complex_color(s: &str, buf: &mut String) -> Result<(), Error> {
-    let (color_code, s) = s.strip_prefix("on-").map_or(("38;", s), |s| ("48;", s));
+    let (color_code, s) = match (s.strip_prefix("on-"), s.strip_prefix("ul-")) {
+        (Some(s), _) => ("48;", s),
+        (_, Some(s)) => ("58;", s),
+        _ => ("38;", s),
+    };
     buf.push_str(color_code);
 
     if let Some(s) = s.strip_prefix('#') {
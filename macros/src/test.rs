@@ -10,9 +10,9 @@ fn unwrap_str() {
         (r#"""""#, Some(String(r#"""#))),
         (r#""Shouldn't work"#, None),
         (r#"" Also Shouldn't wor"k"#, None),
-        (r##"r#""""#"##, Some(RawString(r#""""#, 1))),
-        (r###"r##"#""#"##"###, Some(RawString(r##"#""#"##, 2))),
-        (r###"r##"#""#"#"###, None),
+        ("r#\"\"\"\"#", Some(RawString(r#""""#, 1))),
+        ("r##\"#\"\"#\"##", Some(RawString("#\"\"#", 2))),
+        (r##"r##"#""#"#"##, None),
     ] {
         assert_eq!(unwrap_string(test), result);
     }
@@ -29,7 +29,7 @@ fn raw_string() {
         create_raw_string("🚋 🏥 🤐 💷 🌛", 0),
         r#"r"🚋 🏥 🤐 💷 🌛""#
     );
-    assert_eq!(create_raw_string("", 5), r######"r#####""#####"######);
+    assert_eq!(create_raw_string("", 5), "r#####\"\"#####");
 }
 
 #[test]
@@ -40,7 +40,7 @@ fn sgr_string_general() {
         ("\x1b", Ok("\x1b")),
         ("\u{1f604} ☀ ☁ ☂", Ok("\u{1f604} ☀ ☁ ☂")),
     ] {
-        test_eq(test, result)
+        test_eq(test, result);
     }
 }
 
@@ -52,7 +52,7 @@ fn compiler_pass_off_undetected() {
         ("{with text", Ok("{with text")),
         ("with text{with text", Ok("with text{with text")),
     ] {
-        test_eq(test, result)
+        test_eq(test, result);
     }
 }
 #[test]
@@ -66,7 +66,7 @@ fn compiler_pass_off() {
         (r"\x", Err(CompilerPassOff)),
         (r"\x'", Err(CompilerPassOff)),
     ] {
-        test_eq(test, result)
+        test_eq(test, result);
     }
 }
 
@@ -94,7 +94,7 @@ fn skip_whitespace() {
             Ok("\n\r\t"),
         ),
     ] {
-        test_eq(test, result)
+        test_eq(test, result);
     }
 }
 
@@ -105,7 +105,7 @@ fn escapes() {
         "#,
         Ok("\' \" \x00 \n \r \t \\ \0 \u{0}\
         "),
-    )
+    );
 }
 
 #[test]
@@ -129,7 +129,7 @@ fn param_errors() {
         "bracket {[yeah}",
     ] {
         let result = sgr_string(test, check_curly);
-        assert!(result.is_err(), "Unexpected value: {result:#?}")
+        assert!(result.is_err(), "Unexpected value: {result:#?}");
     }
 }
 fn test_eq(test: &str, result: Result<&str, Error>) {
@@ -139,7 +139,7 @@ fn test_eq(test: &str, result: Result<&str, Error>) {
             Err(result) => panic!("\"{test}\" does not eq {result:#?}"),
         },
         Err(test) => match result {
-            Ok(result) => panic!("{test:#?} does not eq {result}",),
+            Ok(result) => panic!("{test:#?} does not eq {result}"),
             Err(result) => assert_eq!(test, result),
         },
     }
@@ -0,0 +1,55 @@
+# Generates src/width/tables.rs from python's unicodedata
+
+import unicodedata
+
+print('Generating start')
+
+
+def ranges(predicate):
+    '''Ranges of code points matching the predicate, unassigned code points are merged over'''
+    found = []
+    start = last = None
+    for code in range(0x110000):
+        if predicate(code):
+            if start is None:
+                start = code
+            last = code
+        elif unicodedata.category(chr(code)) != 'Cn' and start is not None:
+            found.append((start, last))
+            start = None
+    if start is not None:
+        found.append((start, last))
+    return found
+
+
+def is_zero_width(code):
+    category = unicodedata.category(chr(code))
+    # soft hyphens are usually displayed
+    return category in ('Mn', 'Me') or (category == 'Cf' and code != 0xAD) or 0x1160 <= code <= 0x11FF
+
+
+def is_wide(code):
+    if unicodedata.category(chr(code)) == 'Cn':
+        # unassigned code points in the CJK planes default to wide
+        return 0x20000 <= code <= 0x3FFFD
+    return unicodedata.east_asian_width(chr(code)) in ('W', 'F')
+
+
+def table(name, doc, found):
+    lines = [f'/// {doc}', '#[rustfmt::skip]', f'pub const {name}: &[(u32, u32)] = &[']
+    for i in range(0, len(found), 4):
+        lines.append('    ' + ' '.join(f'(0x{start:05X}, 0x{end:05X}),' for start, end in found[i:i + 4]))
+    lines.append('];')
+    return '\n'.join(lines)
+
+
+with open('./src/width/tables.rs', 'w') as tables_file:
+    tables_file.write(
+        f'// Generated by scripts/gen_width_tables.py, Unicode {unicodedata.unidata_version}\n\n'
+        + table('ZERO_WIDTH', 'Nonspacing, enclosing & format characters', ranges(is_zero_width))
+        + '\n'
+        + table('WIDE', 'East Asian wide & fullwidth characters', ranges(is_wide))
+        + '\n'
+    )
+
+print('Generating complete')
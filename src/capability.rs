@@ -0,0 +1,198 @@
+use core::sync::atomic::{AtomicBool, Ordering};
+#[cfg(feature = "std")]
+use std::{
+    env,
+    io::{self, IsTerminal},
+};
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+
+use crate::{byte_to_basic, rgb_to_basic, rgb_to_byte, CapableBuilder, Param};
+
+/// Whether SGR codes are written by default, see [`set_sgr_enabled`]
+static SGR_ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// Sets whether SGR codes are written, for the whole process
+///
+/// Newly created [`SGRWriter`](crate::SGRWriter)s are enabled according to this, and so
+/// are the [`Display`](core::fmt::Display) implementations of all types within this crate.
+/// When disabled only text is written.
+///
+/// Enabled by default
+///
+/// # Examples
+///
+/// Respecting `NO_COLOR` & a `--color=never` flag:
+///
+///```rust
+///use easy_sgr::{set_sgr_enabled, Color::*, ColorSupport, EasySGR};
+///
+///let color_never = true;
+///set_sgr_enabled(!color_never && ColorSupport::detect() != ColorSupport::None);
+///
+///assert_eq!("text".color(RedFg).to_string(), "text");
+///```
+pub fn set_sgr_enabled(enabled: bool) {
+    SGR_ENABLED.store(enabled, Ordering::Relaxed);
+}
+/// Returns whether SGR codes are written, for the whole process
+///
+/// See [`set_sgr_enabled`]
+#[must_use]
+pub fn is_sgr_enabled() -> bool {
+    SGR_ENABLED.load(Ordering::Relaxed)
+}
+
+/// The level of color support a terminal has
+///
+/// Used by [`SGRWriter`](crate::SGRWriter) to downgrade colors the terminal
+/// is unable to display into the closest color it can display
+///
+/// Variants are ordered from least to most support
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{Color::*, ColorSupport, SGRWriter};
+///
+///let mut writer = SGRWriter::from(String::new());
+///writer.color_support = ColorSupport::Ansi256;
+///writer.inline_sgr(&RgbFg(255, 0, 0)).unwrap();
+///
+///assert_eq!(writer.internal(), "\x1b[38;5;196m");
+///```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ColorSupport {
+    /// No colors are supported, all color codes are removed
+    None,
+    /// Only the 16 basic colors, `30`-`37`, `90`-`97` & their backgrounds
+    Basic16,
+    /// The 256 color palette, `38;5;<n>`
+    Ansi256,
+    /// Any 24 bit color, `38;2;<r>;<g>;<b>`
+    #[default]
+    TrueColor,
+}
+impl ColorSupport {
+    /// Detects the color support of the standard output
+    ///
+    /// See [`ColorSupport::detect_for`]
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn detect() -> Self {
+        Self::detect_for(&io::stdout())
+    }
+    /// Detects the color support of the given stream
+    ///
+    /// Uses the stream's [`IsTerminal`] implementation along with the
+    /// environment variables read by [`ColorSupport::from_env`]
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn detect_for(stream: &impl IsTerminal) -> Self {
+        Self::from_env(stream.is_terminal(), |key| env::var(key).ok())
+    }
+    /// Determines color support from whether the output is a terminal and
+    /// the given environment lookup
+    ///
+    /// The variables used are:
+    ///
+    /// - `NO_COLOR`, when set and not empty no colors are supported
+    /// - `CLICOLOR_FORCE`, when set and not `0` colors are supported even if not a terminal
+    /// - `TERM`, colors are not supported when `dumb`, 256 colors when containing `256color`
+    /// - `COLORTERM`, true colors are supported when `truecolor` or `24bit`
+    #[must_use]
+    #[cfg(feature = "alloc")]
+    pub fn from_env(is_terminal: bool, var: impl Fn(&str) -> Option<String>) -> Self {
+        let set = |key| var(key).filter(|value| !value.is_empty());
+        if set("NO_COLOR").is_some() {
+            return Self::None;
+        }
+        let forced = set("CLICOLOR_FORCE").is_some_and(|value| value != "0");
+        if !is_terminal && !forced {
+            return Self::None;
+        }
+        let term = set("TERM").unwrap_or_default();
+        if matches!(set("COLORTERM").as_deref(), Some("truecolor" | "24bit"))
+            || term.ends_with("-direct")
+        {
+            Self::TrueColor
+        } else if term.contains("256color") {
+            Self::Ansi256
+        } else if term == "dumb" && !forced {
+            Self::None
+        } else {
+            Self::Basic16
+        }
+    }
+    /// Rewrites the given parameters so they only use colors within this level of support
+    ///
+    /// Parameters that are not colors are left untouched.
+    /// When colors are not supported at all, extended colors written as custom codes,
+    /// i.e. `38;5;208`, are removed as well
+    pub(crate) fn downgrade(self, builder: &mut impl CapableBuilder) {
+        use Param::*;
+        if self == Self::TrueColor {
+            return;
+        }
+        let params = builder.params_mut();
+        let mut kept = 0;
+        // the codes left of a custom extended color
+        let mut color_len = 0;
+        for i in 0..params.len() {
+            let param = params[i];
+            if !matches!(param, Code(_)) {
+                color_len = 0;
+            }
+            let keep = match (self, param) {
+                (_, Code(_)) if color_len > 0 => {
+                    color_len -= 1;
+                    false
+                }
+                (Self::None, Byte(..) | Rgb(..)) => false,
+                (Self::None, Code(38 | 48 | 58)) => {
+                    color_len = match params.get(i + 1) {
+                        Some(Code(5)) => 2,
+                        Some(Code(2)) => 4,
+                        _ => 0,
+                    };
+                    false
+                }
+                (Self::None, Code(code)) => !is_color(code),
+                _ => true,
+            };
+            if keep {
+                params[kept] = param;
+                kept += 1;
+            }
+        }
+        builder.truncate(kept);
+        for param in builder.params_mut() {
+            *param = match (self, *param) {
+                (Self::Ansi256, Rgb(base, r, g, b)) => Byte(base, rgb_to_byte(r, g, b)),
+                // underline colors can only be extended, so use the basic colors' indexes
+                (Self::Basic16, Rgb(58, r, g, b)) => Byte(58, rgb_to_basic(r, g, b)),
+                (Self::Basic16, Byte(58, n)) => Byte(58, byte_to_basic(n)),
+                (Self::Basic16, Rgb(base, r, g, b)) => {
+                    Code(basic_code(base, rgb_to_basic(r, g, b)))
+                }
+                (Self::Basic16, Byte(base, n)) => Code(basic_code(base, byte_to_basic(n))),
+                (_, param) => param,
+            };
+        }
+    }
+}
+/// Whether the given code, on its own, sets a color
+const fn is_color(code: u8) -> bool {
+    matches!(code, 30..=37 | 39 | 40..=47 | 49 | 59 | 90..=97 | 100..=107)
+}
+/// Returns the code of a basic color
+///
+/// `base` being either `38` or `48`, and `n` being the index of the color, `0`-`15`
+const fn basic_code(base: u8, n: u8) -> u8 {
+    if n < 8 {
+        base - 8 + n
+    } else {
+        base + 44 + n
+    }
+}
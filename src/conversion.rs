@@ -0,0 +1,528 @@
+use core::{error::Error, fmt::Display};
+
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String};
+
+use crate::{Color, ColorKind};
+
+/// The values each level of the 256 color palette's 6x6x6 cube is made of
+const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+/// The xterm defaults of the 16 basic colors
+///
+/// Ordered the same as the palette, black, red, green, yellow,
+/// blue, magenta, cyan & white followed by their bright variants
+pub const BASIC_COLORS: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+/// Returns the index of the closest color within the 256 color palette
+///
+/// Only the 6x6x6 cube & the grayscale ramp are used, as the first
+/// 16 colors differ between terminals
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::rgb_to_byte;
+///
+///assert_eq!(rgb_to_byte(255, 0, 0), 196);
+///assert_eq!(rgb_to_byte(128, 128, 128), 244);
+///```
+#[must_use]
+pub fn rgb_to_byte(r: u8, g: u8, b: u8) -> u8 {
+    let level = |n: u8| match n {
+        0..=47 => 0,
+        48..=114 => 1,
+        n => (n - 35) / 40,
+    };
+    let (lr, lg, lb) = (level(r), level(g), level(b));
+    let cube = 16 + 36 * lr + 6 * lg + lb;
+    let cube_rgb = (
+        CUBE_LEVELS[lr as usize],
+        CUBE_LEVELS[lg as usize],
+        CUBE_LEVELS[lb as usize],
+    );
+
+    let average = (u16::from(r) + u16::from(g) + u16::from(b)) / 3;
+    let gray = u8::try_from(average.saturating_sub(3) / 10).map_or(23, |gray| gray.min(23));
+    let gray_value = 8 + 10 * gray;
+
+    if distance((r, g, b), (gray_value, gray_value, gray_value)) < distance((r, g, b), cube_rgb) {
+        232 + gray
+    } else {
+        cube
+    }
+}
+/// Returns the color of the given index within the 256 color palette
+///
+/// The first 16 colors use the xterm defaults, see [`BASIC_COLORS`]
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::byte_to_rgb;
+///
+///assert_eq!(byte_to_rgb(196), (255, 0, 0));
+///assert_eq!(byte_to_rgb(232), (8, 8, 8));
+///```
+#[must_use]
+pub const fn byte_to_rgb(n: u8) -> (u8, u8, u8) {
+    match n {
+        0..=15 => BASIC_COLORS[n as usize],
+        16..=231 => {
+            let n = n - 16;
+            (
+                CUBE_LEVELS[(n / 36) as usize],
+                CUBE_LEVELS[(n / 6 % 6) as usize],
+                CUBE_LEVELS[(n % 6) as usize],
+            )
+        }
+        n => {
+            let value = 8 + 10 * (n - 232);
+            (value, value, value)
+        }
+    }
+}
+/// Returns the index, `0`-`15`, of the closest basic color to
+/// the given index within the 256 color palette
+#[must_use]
+pub fn byte_to_basic(n: u8) -> u8 {
+    if n < 16 {
+        n
+    } else {
+        let (r, g, b) = byte_to_rgb(n);
+        rgb_to_basic(r, g, b)
+    }
+}
+/// Returns the index, `0`-`15`, of the closest of the 16 basic colors
+///
+/// `0`-`7` being the standard colors & `8`-`15` being their bright variants
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::rgb_to_basic;
+///
+///assert_eq!(rgb_to_basic(250, 10, 10), 9);
+///```
+#[must_use]
+pub fn rgb_to_basic(r: u8, g: u8, b: u8) -> u8 {
+    closest(16, (r, g, b))
+}
+/// Returns the index, `0`-`7`, of the closest of the 8 standard colors
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::rgb_to_standard;
+///
+///assert_eq!(rgb_to_standard(250, 10, 10), 1);
+///```
+#[must_use]
+pub fn rgb_to_standard(r: u8, g: u8, b: u8) -> u8 {
+    closest(8, (r, g, b))
+}
+/// Parses a hex color, either `#rrggbb` or `#rgb`
+///
+/// The `#` is optional & digits are case insensitive
+///
+/// # Errors
+///
+/// Returns an error if the string is of the wrong length
+/// or contains a character that is not a hex digit
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::parse_hex;
+///
+///assert_eq!(parse_hex("#ff8000"), Ok((255, 128, 0)));
+///assert_eq!(parse_hex("F80"), Ok((255, 136, 0)));
+///```
+pub fn parse_hex(s: &str) -> Result<(u8, u8, u8), ParseHexError> {
+    let hex = s.strip_prefix('#').unwrap_or(s);
+    let mut digits = [0; 6];
+    let mut len = 0;
+    for ch in hex.chars() {
+        let n = ch
+            .to_digit(16)
+            .and_then(|n| u8::try_from(n).ok())
+            .ok_or(ParseHexError::Digit(ch))?;
+        if let Some(digit) = digits.get_mut(len) {
+            *digit = n;
+        }
+        len += 1;
+    }
+    match (len, digits) {
+        (3, [r, g, b, ..]) => Ok((r * 17, g * 17, b * 17)),
+        (6, [r1, r2, g1, g2, b1, b2]) => Ok((r1 * 16 + r2, g1 * 16 + g2, b1 * 16 + b2)),
+        _ => Err(ParseHexError::Len(len)),
+    }
+}
+/// Formats a color as a lowercase `#rrggbb` hex string
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::to_hex;
+///
+///assert_eq!(to_hex(255, 128, 0), "#ff8000");
+///```
+#[must_use]
+#[cfg(feature = "alloc")]
+pub fn to_hex(r: u8, g: u8, b: u8) -> String {
+    format!("#{r:02x}{g:02x}{b:02x}")
+}
+/// An error encountered while trying to parse a hex color
+#[derive(Debug, PartialEq, Eq)]
+pub enum ParseHexError {
+    /// Wrong number of digits
+    ///
+    /// i.e. `#ff` or `#ff00ff00`
+    Len(usize),
+    /// A character that is not a hex digit
+    ///
+    /// i.e. `#ggg`
+    Digit(char),
+}
+impl Display for ParseHexError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Len(n) => write!(f, "Found wrong number of hex digits: {n}. Needed 3 or 6"),
+            Self::Digit(ch) => write!(f, "Invalid hex digit: {ch}"),
+        }
+    }
+}
+impl Error for ParseHexError {}
+impl ColorKind {
+    /// Creates a [`ColorKind::Rgb`] from a hex color, see [`parse_hex`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the hex color is invalid
+    pub fn from_hex(s: &str) -> Result<Self, ParseHexError> {
+        parse_hex(s).map(|(r, g, b)| Self::Rgb(r, g, b))
+    }
+    /// Formats the color as a `#rrggbb` hex string
+    ///
+    /// Returns `None` if the color is [`ColorKind::None`] or [`ColorKind::Default`]
+    #[must_use]
+    #[cfg(feature = "alloc")]
+    pub fn to_hex(&self) -> Option<String> {
+        self.rgb().map(|(r, g, b)| to_hex(r, g, b))
+    }
+    /// Returns the red, green & blue values of the color
+    ///
+    /// Standard colors use the xterm defaults, see [`BASIC_COLORS`].
+    /// Returns `None` if the color is [`ColorKind::None`] or [`ColorKind::Default`]
+    #[must_use]
+    pub const fn rgb(&self) -> Option<(u8, u8, u8)> {
+        match (self, self.index()) {
+            (&Self::Rgb(r, g, b), _) => Some((r, g, b)),
+            (_, Some(n)) => Some(byte_to_rgb(n)),
+            (_, None) => None,
+        }
+    }
+    /// Returns the index of the color within the 256 color palette
+    ///
+    /// Standard & bright colors being `0`-`15`.
+    /// Returns `None` for [`ColorKind::Rgb`], [`ColorKind::None`] & [`ColorKind::Default`]
+    #[must_use]
+    pub const fn index(&self) -> Option<u8> {
+        use ColorKind::*;
+        match *self {
+            Black => Some(0),
+            Red => Some(1),
+            Green => Some(2),
+            Yellow => Some(3),
+            Blue => Some(4),
+            Magenta => Some(5),
+            Cyan => Some(6),
+            White => Some(7),
+            BrightBlack => Some(8),
+            BrightRed => Some(9),
+            BrightGreen => Some(10),
+            BrightYellow => Some(11),
+            BrightBlue => Some(12),
+            BrightMagenta => Some(13),
+            BrightCyan => Some(14),
+            BrightWhite => Some(15),
+            Byte(n) => Some(n),
+            Rgb(..) | None | Default => Option::None,
+        }
+    }
+    /// Converts the color into a [`ColorKind::Rgb`]
+    ///
+    /// [`ColorKind::None`] & [`ColorKind::Default`] are returned unchanged
+    #[must_use]
+    pub fn to_rgb(&self) -> Self {
+        self.rgb()
+            .map_or_else(|| self.clone(), |(r, g, b)| Self::Rgb(r, g, b))
+    }
+    /// Converts a [`ColorKind::Rgb`] into the closest [`ColorKind::Byte`]
+    ///
+    /// All other colors are already within the 256 color palette,
+    /// so are returned unchanged
+    #[must_use]
+    pub fn to_byte(&self) -> Self {
+        match *self {
+            Self::Rgb(r, g, b) => Self::Byte(rgb_to_byte(r, g, b)),
+            ref kind => kind.clone(),
+        }
+    }
+    /// Converts a [`ColorKind::Byte`], [`ColorKind::Rgb`] or bright color
+    /// into the closest of the 8 standard colors
+    ///
+    /// All other colors are returned unchanged
+    #[must_use]
+    pub fn to_standard(&self) -> Self {
+        use ColorKind::*;
+        match (self, self.rgb()) {
+            (Black | Red | Green | Yellow | Blue | Magenta | Cyan | White, _)
+            | (_, Option::None) => self.clone(),
+            (_, Some((r, g, b))) => basic_kind(rgb_to_standard(r, g, b)),
+        }
+    }
+    /// Converts a [`ColorKind::Byte`] or [`ColorKind::Rgb`]
+    /// into the closest of the 16 basic colors, standard or bright
+    ///
+    /// All other colors are returned unchanged
+    #[must_use]
+    pub fn to_basic(&self) -> Self {
+        match *self {
+            Self::Byte(n) => basic_kind(byte_to_basic(n)),
+            Self::Rgb(r, g, b) => basic_kind(rgb_to_basic(r, g, b)),
+            ref kind => kind.clone(),
+        }
+    }
+}
+/// Returns the [`ColorKind`] of a basic color's index, `0`-`15`
+pub(crate) const fn basic_kind(n: u8) -> ColorKind {
+    use ColorKind::*;
+    match n {
+        0 => Black,
+        1 => Red,
+        2 => Green,
+        3 => Yellow,
+        4 => Blue,
+        5 => Magenta,
+        6 => Cyan,
+        7 => White,
+        8 => BrightBlack,
+        9 => BrightRed,
+        10 => BrightGreen,
+        11 => BrightYellow,
+        12 => BrightBlue,
+        13 => BrightMagenta,
+        14 => BrightCyan,
+        _ => BrightWhite,
+    }
+}
+impl Color {
+    /// Creates a [`Color::RgbFg`] from a hex color, see [`parse_hex`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the hex color is invalid
+    pub fn from_hex_fg(s: &str) -> Result<Self, ParseHexError> {
+        parse_hex(s).map(|(r, g, b)| Self::RgbFg(r, g, b))
+    }
+    /// Creates a [`Color::RgbBg`] from a hex color, see [`parse_hex`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the hex color is invalid
+    pub fn from_hex_bg(s: &str) -> Result<Self, ParseHexError> {
+        parse_hex(s).map(|(r, g, b)| Self::RgbBg(r, g, b))
+    }
+    /// Creates a [`Color::RgbUl`] from a hex color, see [`parse_hex`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the hex color is invalid
+    pub fn from_hex_ul(s: &str) -> Result<Self, ParseHexError> {
+        parse_hex(s).map(|(r, g, b)| Self::RgbUl(r, g, b))
+    }
+    /// Formats the color as a `#rrggbb` hex string
+    ///
+    /// Returns `None` for [`Color::DefaultFg`], [`Color::DefaultBg`] & [`Color::DefaultUl`]
+    #[must_use]
+    #[cfg(feature = "alloc")]
+    pub fn to_hex(&self) -> Option<String> {
+        self.kind().to_hex()
+    }
+    /// Returns the red, green & blue values of the color
+    ///
+    /// See [`ColorKind::rgb`]
+    #[must_use]
+    pub const fn rgb(&self) -> Option<(u8, u8, u8)> {
+        self.kind().rgb()
+    }
+    /// Converts the color into a [`Color::RgbFg`] or [`Color::RgbBg`]
+    ///
+    /// See [`ColorKind::to_rgb`]
+    #[must_use]
+    pub fn to_rgb(&self) -> Self {
+        self.map_kind(ColorKind::to_rgb)
+    }
+    /// Converts a [`Color::RgbFg`] or [`Color::RgbBg`] into the closest
+    /// [`Color::ByteFg`] or [`Color::ByteBg`]
+    ///
+    /// See [`ColorKind::to_byte`]
+    #[must_use]
+    pub fn to_byte(&self) -> Self {
+        self.map_kind(ColorKind::to_byte)
+    }
+    /// Converts a byte, rgb or bright color into the closest of the 8 standard colors
+    ///
+    /// See [`ColorKind::to_standard`]
+    #[must_use]
+    pub fn to_standard(&self) -> Self {
+        self.map_kind(ColorKind::to_standard)
+    }
+    /// Converts a byte or rgb color into the closest of the 16 basic colors
+    ///
+    /// See [`ColorKind::to_basic`]
+    #[must_use]
+    pub fn to_basic(&self) -> Self {
+        self.map_kind(ColorKind::to_basic)
+    }
+    /// Returns the [`ColorKind`] of the color
+    const fn kind(&self) -> ColorKind {
+        self.split().0
+    }
+    /// Converts the [`ColorKind`] of the color, keeping what it colors
+    fn map_kind(&self, f: impl FnOnce(&ColorKind) -> ColorKind) -> Self {
+        use {Color::*, ColorKind::*, Layer::*};
+        let (kind, layer) = self.split();
+        match (layer, f(&kind)) {
+            (Foreground, Black) => BlackFg,
+            (Foreground, Red) => RedFg,
+            (Foreground, Green) => GreenFg,
+            (Foreground, Yellow) => YellowFg,
+            (Foreground, Blue) => BlueFg,
+            (Foreground, Magenta) => MagentaFg,
+            (Foreground, Cyan) => CyanFg,
+            (Foreground, White) => WhiteFg,
+            (Foreground, BrightBlack) => BrightBlackFg,
+            (Foreground, BrightRed) => BrightRedFg,
+            (Foreground, BrightGreen) => BrightGreenFg,
+            (Foreground, BrightYellow) => BrightYellowFg,
+            (Foreground, BrightBlue) => BrightBlueFg,
+            (Foreground, BrightMagenta) => BrightMagentaFg,
+            (Foreground, BrightCyan) => BrightCyanFg,
+            (Foreground, BrightWhite) => BrightWhiteFg,
+            (Foreground, Byte(n)) => ByteFg(n),
+            (Foreground, Rgb(r, g, b)) => RgbFg(r, g, b),
+            (Foreground, None | Default) => DefaultFg,
+
+            (Background, Black) => BlackBg,
+            (Background, Red) => RedBg,
+            (Background, Green) => GreenBg,
+            (Background, Yellow) => YellowBg,
+            (Background, Blue) => BlueBg,
+            (Background, Magenta) => MagentaBg,
+            (Background, Cyan) => CyanBg,
+            (Background, White) => WhiteBg,
+            (Background, BrightBlack) => BrightBlackBg,
+            (Background, BrightRed) => BrightRedBg,
+            (Background, BrightGreen) => BrightGreenBg,
+            (Background, BrightYellow) => BrightYellowBg,
+            (Background, BrightBlue) => BrightBlueBg,
+            (Background, BrightMagenta) => BrightMagentaBg,
+            (Background, BrightCyan) => BrightCyanBg,
+            (Background, BrightWhite) => BrightWhiteBg,
+            (Background, Byte(n)) => ByteBg(n),
+            (Background, Rgb(r, g, b)) => RgbBg(r, g, b),
+            (Background, None | Default) => DefaultBg,
+
+            (Underline, Rgb(r, g, b)) => RgbUl(r, g, b),
+            (Underline, None | Default) => DefaultUl,
+            (Underline, kind) => ByteUl(kind.index().unwrap_or_default()),
+        }
+    }
+}
+impl Color {
+    /// Returns the [`ColorKind`] of the color along with what it colors
+    pub(crate) const fn split(&self) -> (ColorKind, Layer) {
+        use {Color::*, ColorKind::*, Layer::*};
+        match *self {
+            BlackFg => (Black, Foreground),
+            RedFg => (Red, Foreground),
+            GreenFg => (Green, Foreground),
+            YellowFg => (Yellow, Foreground),
+            BlueFg => (Blue, Foreground),
+            MagentaFg => (Magenta, Foreground),
+            CyanFg => (Cyan, Foreground),
+            WhiteFg => (White, Foreground),
+            BrightBlackFg => (BrightBlack, Foreground),
+            BrightRedFg => (BrightRed, Foreground),
+            BrightGreenFg => (BrightGreen, Foreground),
+            BrightYellowFg => (BrightYellow, Foreground),
+            BrightBlueFg => (BrightBlue, Foreground),
+            BrightMagentaFg => (BrightMagenta, Foreground),
+            BrightCyanFg => (BrightCyan, Foreground),
+            BrightWhiteFg => (BrightWhite, Foreground),
+            ByteFg(n) => (Byte(n), Foreground),
+            RgbFg(r, g, b) => (Rgb(r, g, b), Foreground),
+            DefaultFg => (Default, Foreground),
+
+            BlackBg => (Black, Background),
+            RedBg => (Red, Background),
+            GreenBg => (Green, Background),
+            YellowBg => (Yellow, Background),
+            BlueBg => (Blue, Background),
+            MagentaBg => (Magenta, Background),
+            CyanBg => (Cyan, Background),
+            WhiteBg => (White, Background),
+            BrightBlackBg => (BrightBlack, Background),
+            BrightRedBg => (BrightRed, Background),
+            BrightGreenBg => (BrightGreen, Background),
+            BrightYellowBg => (BrightYellow, Background),
+            BrightBlueBg => (BrightBlue, Background),
+            BrightMagentaBg => (BrightMagenta, Background),
+            BrightCyanBg => (BrightCyan, Background),
+            BrightWhiteBg => (BrightWhite, Background),
+            ByteBg(n) => (Byte(n), Background),
+            RgbBg(r, g, b) => (Rgb(r, g, b), Background),
+            DefaultBg => (Default, Background),
+
+            ByteUl(n) => (Byte(n), Underline),
+            RgbUl(r, g, b) => (Rgb(r, g, b), Underline),
+            DefaultUl => (Default, Underline),
+        }
+    }
+}
+/// What a [`Color`] colors
+pub(crate) enum Layer {
+    Foreground,
+    Background,
+    Underline,
+}
+/// Returns the index of the closest of the first `count` basic colors
+fn closest(count: u8, rgb: (u8, u8, u8)) -> u8 {
+    (0..count)
+        .min_by_key(|&i| distance(rgb, BASIC_COLORS[i as usize]))
+        .unwrap_or(0)
+}
+/// The squared distance between two colors
+fn distance((r1, g1, b1): (u8, u8, u8), (r2, g2, b2): (u8, u8, u8)) -> u32 {
+    [(r1, r2), (g1, g2), (b1, b2)]
+        .into_iter()
+        .map(|(a, b)| u32::from(a.abs_diff(b)).pow(2))
+        .sum()
+}
@@ -1,4 +1,9 @@
-use std::{error::Error, fmt::Display, num::ParseIntError, str::FromStr};
+use core::{error::Error, fmt::Display, num::ParseIntError, str::FromStr};
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
 
 use crate::{Color, Seq, Style};
 
@@ -30,6 +35,24 @@ impl FromStr for Style {
             "Inverse" => Ok(Self::Inverse),
             "Hidden" => Ok(Self::Hidden),
             "Strikethrough" => Ok(Self::Strikethrough),
+            "DoubleUnderline" => Ok(Self::DoubleUnderline),
+            "CurlyUnderline" => Ok(Self::CurlyUnderline),
+            "DottedUnderline" => Ok(Self::DottedUnderline),
+            "DashedUnderline" => Ok(Self::DashedUnderline),
+            "DoublyUnderlined" => Ok(Self::DoublyUnderlined),
+            "ProportionalSpacing" => Ok(Self::ProportionalSpacing),
+            "Framed" => Ok(Self::Framed),
+            "Encircled" => Ok(Self::Encircled),
+            "Overline" => Ok(Self::Overline),
+            "PrimaryFont" => Ok(Self::PrimaryFont),
+            "Fraktur" => Ok(Self::Fraktur),
+            "IdeogramUnderline" => Ok(Self::IdeogramUnderline),
+            "IdeogramDoubleUnderline" => Ok(Self::IdeogramDoubleUnderline),
+            "IdeogramOverline" => Ok(Self::IdeogramOverline),
+            "IdeogramDoubleOverline" => Ok(Self::IdeogramDoubleOverline),
+            "IdeogramStress" => Ok(Self::IdeogramStress),
+            "Superscript" => Ok(Self::Superscript),
+            "Subscript" => Ok(Self::Subscript),
             "NotBold" => Ok(Self::NotBold),
             "NotDim" => Ok(Self::NotDim),
             "NotItalic" => Ok(Self::NotItalic),
@@ -38,7 +61,19 @@ impl FromStr for Style {
             "NotInverse" => Ok(Self::NotInverse),
             "NotHidden" => Ok(Self::NotHidden),
             "NotStrikethrough" => Ok(Self::NotStrikethrough),
-            _ => Err(ParseStyleError),
+            "NotProportionalSpacing" => Ok(Self::NotProportionalSpacing),
+            "NotFramed" => Ok(Self::NotFramed),
+            "NotEncircled" => Ok(Self::NotEncircled),
+            "NotOverline" => Ok(Self::NotOverline),
+            "NotIdeogram" => Ok(Self::NotIdeogram),
+            "NotSuperscript" => Ok(Self::NotSuperscript),
+            "NotSubscript" => Ok(Self::NotSubscript),
+            _ => s
+                .strip_prefix("AlternateFont(")
+                .and_then(|s| s.strip_suffix(')'))
+                .and_then(|n| n.parse().ok())
+                .map(Self::AlternateFont)
+                .ok_or(ParseStyleError),
         }
     }
 }
@@ -59,6 +94,14 @@ impl FromStr for Color {
             "MagentaFg" => Ok(MagentaFg),
             "CyanFg" => Ok(CyanFg),
             "WhiteFg" => Ok(WhiteFg),
+            "BrightBlackFg" => Ok(BrightBlackFg),
+            "BrightRedFg" => Ok(BrightRedFg),
+            "BrightGreenFg" => Ok(BrightGreenFg),
+            "BrightYellowFg" => Ok(BrightYellowFg),
+            "BrightBlueFg" => Ok(BrightBlueFg),
+            "BrightMagentaFg" => Ok(BrightMagentaFg),
+            "BrightCyanFg" => Ok(BrightCyanFg),
+            "BrightWhiteFg" => Ok(BrightWhiteFg),
             "DefaultFg" => Ok(DefaultFg),
             "BlackBg" => Ok(BlackBg),
             "RedBg" => Ok(RedBg),
@@ -68,7 +111,16 @@ impl FromStr for Color {
             "MagentaBg" => Ok(MagentaBg),
             "CyanBg" => Ok(CyanBg),
             "WhiteBg" => Ok(WhiteBg),
+            "BrightBlackBg" => Ok(BrightBlackBg),
+            "BrightRedBg" => Ok(BrightRedBg),
+            "BrightGreenBg" => Ok(BrightGreenBg),
+            "BrightYellowBg" => Ok(BrightYellowBg),
+            "BrightBlueBg" => Ok(BrightBlueBg),
+            "BrightMagentaBg" => Ok(BrightMagentaBg),
+            "BrightCyanBg" => Ok(BrightCyanBg),
+            "BrightWhiteBg" => Ok(BrightWhiteBg),
             "DefaultBg" => Ok(DefaultBg),
+            "DefaultUl" => Ok(DefaultUl),
             _ => match s.get(..5) {
                 Some("RgbFg") => {
                     let parts = resolve_rgb(s)?;
@@ -78,9 +130,14 @@ impl FromStr for Color {
                     let parts = resolve_rgb(s)?;
                     Ok(RgbBg(parts.0, parts.1, parts.2))
                 }
+                Some("RgbUl") => {
+                    let parts = resolve_rgb(s)?;
+                    Ok(RgbUl(parts.0, parts.1, parts.2))
+                }
                 Some(_) => match s.get(..6) {
                     Some("ByteFg") => Ok(ByteFg(resolve_byte(s)?)),
                     Some("ByteBg") => Ok(ByteBg(resolve_byte(s)?)),
+                    Some("ByteUl") => Ok(ByteUl(resolve_byte(s)?)),
                     _ => Err(ParseColorError::Invalid(s.to_string())),
                 },
                 None => Err(ParseColorError::Invalid(s.to_string())),
@@ -111,7 +168,7 @@ pub enum ParseColorError {
     Len(usize),
 }
 impl Display for ParseColorError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::Invalid(s) => write!(f, "Invalid string: {s}"),
             Self::MissingNum(s) => write!(f, "Missing number: {s}"),
@@ -1,8 +1,8 @@
-use std::fmt::Display;
+use core::fmt::Display;
 
-use crate::{EasySGR, SGRBuilder, SGRWriter};
+use crate::{is_sgr_enabled, CapableBuilder, Param, SGRWriter};
 
-/// Implements [`FromStr`](std::str::FromStr) for the [`discrete`](crate::discrete) module
+/// Implements [`FromStr`](core::str::FromStr) for the [`discrete`](crate::discrete) module
 #[cfg(feature = "from-str")]
 pub mod from_str;
 
@@ -10,6 +10,8 @@ pub mod from_str;
 ///
 /// Intended use case is when the `partial` feature is enable
 ///
+/// Nothing is written when SGR codes are [disabled](crate::set_sgr_enabled)
+///
 /// # Examples
 ///
 /// Using it with `partial` enabled:
@@ -27,7 +29,10 @@ pub enum Seq {
     End,
 }
 impl Display for Seq {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if !is_sgr_enabled() {
+            return Ok(());
+        }
         f.write_str(match self {
             Self::Esc => "\x1b[",
             Self::End => "m",
@@ -71,6 +76,52 @@ pub enum Style {
     Hidden,
     /// Represents the SGR code `9`
     Strikethrough,
+    /// Represents the SGR code `4:2`
+    DoubleUnderline,
+    /// Represents the SGR code `4:3`
+    CurlyUnderline,
+    /// Represents the SGR code `4:4`
+    DottedUnderline,
+    /// Represents the SGR code `4:5`
+    DashedUnderline,
+    /// Represents the SGR code `21`
+    ///
+    /// Unlike [`Style::DoubleUnderline`] is understood by older terminals,
+    /// though some instead treat it as [`Style::NotBold`]
+    DoublyUnderlined,
+    /// Represents the SGR code `26`
+    ProportionalSpacing,
+    /// Represents the SGR code `51`
+    Framed,
+    /// Represents the SGR code `52`
+    Encircled,
+    /// Represents the SGR code `53`
+    Overline,
+    /// Represents the SGR code `10`
+    ///
+    /// Undoes the effects of [`Style::AlternateFont`] & [`Style::Fraktur`]
+    PrimaryFont,
+    /// Represents the SGR codes `11`-`19`
+    ///
+    /// Where the font's number, `1`-`9`, is added to `10`.
+    /// Numbers outside of `1`-`9` are clamped
+    AlternateFont(u8),
+    /// Represents the SGR code `20`
+    Fraktur,
+    /// Represents the SGR code `60`
+    IdeogramUnderline,
+    /// Represents the SGR code `61`
+    IdeogramDoubleUnderline,
+    /// Represents the SGR code `62`
+    IdeogramOverline,
+    /// Represents the SGR code `63`
+    IdeogramDoubleOverline,
+    /// Represents the SGR code `64`
+    IdeogramStress,
+    /// Represents the SGR code `73`
+    Superscript,
+    /// Represents the SGR code `74`
+    Subscript,
     /// Represents the SGR code `22`
     ///
     /// Is equivalent to [`Style::NotDim`]
@@ -82,6 +133,8 @@ pub enum Style {
     /// Represents the SGR code `23`
     NotItalic,
     /// Represents the SGR code `24`
+    ///
+    /// Also undoes the effects of the other underline styles
     NotUnderline,
     /// Represents the SGR code `25`
     NotBlinking,
@@ -91,14 +144,38 @@ pub enum Style {
     NotHidden,
     /// Represents the SGR code `29`
     NotStrikethrough,
+    /// Represents the SGR code `50`
+    NotProportionalSpacing,
+    /// Represents the SGR code `54`
+    ///
+    /// Is equivalent to [`Style::NotEncircled`]
+    NotFramed,
+    /// Represents the SGR code `54`
+    ///
+    /// Is equivalent to [`Style::NotFramed`]
+    NotEncircled,
+    /// Represents the SGR code `55`
+    NotOverline,
+    /// Represents the SGR code `65`
+    ///
+    /// Undoes the effects of all ideogram styles
+    NotIdeogram,
+    /// Represents the SGR code `75`
+    ///
+    /// Is equivalent to [`Style::NotSubscript`]
+    NotSuperscript,
+    /// Represents the SGR code `75`
+    ///
+    /// Is equivalent to [`Style::NotSuperscript`]
+    NotSubscript,
 }
 impl Display for Style {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         self.standard_display(f)
     }
 }
 impl DiscreteSGR for Style {
-    fn write(&self, builder: &mut SGRBuilder) {
+    fn write(&self, builder: &mut impl CapableBuilder) {
         use Style::*;
         builder.write_code(match self {
             Reset => 0,
@@ -117,6 +194,30 @@ impl DiscreteSGR for Style {
             NotInverse => 27,
             NotHidden => 28,
             NotStrikethrough => 29,
+            DoublyUnderlined => 21,
+            ProportionalSpacing => 26,
+            Framed => 51,
+            Encircled => 52,
+            Overline => 53,
+            PrimaryFont => 10,
+            AlternateFont(n) => 10 + (*n).clamp(1, 9),
+            Fraktur => 20,
+            IdeogramUnderline => 60,
+            IdeogramDoubleUnderline => 61,
+            IdeogramOverline => 62,
+            IdeogramDoubleOverline => 63,
+            IdeogramStress => 64,
+            Superscript => 73,
+            Subscript => 74,
+            NotProportionalSpacing => 50,
+            NotFramed | NotEncircled => 54,
+            NotOverline => 55,
+            NotIdeogram => 65,
+            NotSuperscript | NotSubscript => 75,
+            DoubleUnderline => return builder.write_sub(4, 2),
+            CurlyUnderline => return builder.write_sub(4, 3),
+            DottedUnderline => return builder.write_sub(4, 4),
+            DashedUnderline => return builder.write_sub(4, 5),
         });
     }
 }
@@ -150,6 +251,22 @@ pub enum Color {
     CyanFg,
     /// Represents the SGR code `37`
     WhiteFg,
+    /// Represents the SGR code `90`
+    BrightBlackFg,
+    /// Represents the SGR code `91`
+    BrightRedFg,
+    /// Represents the SGR code `92`
+    BrightGreenFg,
+    /// Represents the SGR code `93`
+    BrightYellowFg,
+    /// Represents the SGR code `94`
+    BrightBlueFg,
+    /// Represents the SGR code `95`
+    BrightMagentaFg,
+    /// Represents the SGR code `96`
+    BrightCyanFg,
+    /// Represents the SGR code `97`
+    BrightWhiteFg,
     /// Represents the SGR codes `38;5;<n>`
     ///
     /// Where `<n>` is an 8 bit color
@@ -177,6 +294,22 @@ pub enum Color {
     CyanBg,
     /// Represents the SGR code `47`
     WhiteBg,
+    /// Represents the SGR code `100`
+    BrightBlackBg,
+    /// Represents the SGR code `101`
+    BrightRedBg,
+    /// Represents the SGR code `102`
+    BrightGreenBg,
+    /// Represents the SGR code `103`
+    BrightYellowBg,
+    /// Represents the SGR code `104`
+    BrightBlueBg,
+    /// Represents the SGR code `105`
+    BrightMagentaBg,
+    /// Represents the SGR code `106`
+    BrightCyanBg,
+    /// Represents the SGR code `107`
+    BrightWhiteBg,
     /// Represents the SGR codes `48;5;<n>`
     ///
     /// Where `<n>` is an 8 bit color
@@ -187,14 +320,25 @@ pub enum Color {
     RgbBg(u8, u8, u8),
     /// Represents the SGR code `49`
     DefaultBg,
+
+    /// Represents the SGR codes `58;5;<n>`
+    ///
+    /// Where `<n>` is an 8 bit color
+    ByteUl(u8),
+    /// Represents the SGR codes `58;2;<n1>;<n2>;<n3>`
+    ///
+    /// Where `<n1>`,`<n2>`,`<n3>` are 8 bit colors
+    RgbUl(u8, u8, u8),
+    /// Represents the SGR code `59`
+    DefaultUl,
 }
 impl Display for Color {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         self.standard_display(f)
     }
 }
 impl DiscreteSGR for Color {
-    fn write(&self, builder: &mut SGRBuilder) {
+    fn write(&self, builder: &mut impl CapableBuilder) {
         use Color::*;
         match self {
             BlackFg => builder.write_code(30),
@@ -205,8 +349,16 @@ impl DiscreteSGR for Color {
             MagentaFg => builder.write_code(35),
             CyanFg => builder.write_code(36),
             WhiteFg => builder.write_code(37),
-            ByteFg(n) => builder.write_codes(&[38, 5, *n]),
-            RgbFg(r, g, b) => builder.write_codes(&[38, 2, *r, *g, *b]),
+            BrightBlackFg => builder.write_code(90),
+            BrightRedFg => builder.write_code(91),
+            BrightGreenFg => builder.write_code(92),
+            BrightYellowFg => builder.write_code(93),
+            BrightBlueFg => builder.write_code(94),
+            BrightMagentaFg => builder.write_code(95),
+            BrightCyanFg => builder.write_code(96),
+            BrightWhiteFg => builder.write_code(97),
+            ByteFg(n) => builder.write_param(Param::Byte(38, *n)),
+            RgbFg(r, g, b) => builder.write_param(Param::Rgb(38, *r, *g, *b)),
             DefaultFg => builder.write_code(39),
 
             BlackBg => builder.write_code(40),
@@ -217,9 +369,21 @@ impl DiscreteSGR for Color {
             MagentaBg => builder.write_code(45),
             CyanBg => builder.write_code(46),
             WhiteBg => builder.write_code(47),
-            ByteBg(n) => builder.write_codes(&[48, 5, *n]),
-            RgbBg(r, g, b) => builder.write_codes(&[48, 2, *r, *g, *b]),
+            BrightBlackBg => builder.write_code(100),
+            BrightRedBg => builder.write_code(101),
+            BrightGreenBg => builder.write_code(102),
+            BrightYellowBg => builder.write_code(103),
+            BrightBlueBg => builder.write_code(104),
+            BrightMagentaBg => builder.write_code(105),
+            BrightCyanBg => builder.write_code(106),
+            BrightWhiteBg => builder.write_code(107),
+            ByteBg(n) => builder.write_param(Param::Byte(48, *n)),
+            RgbBg(r, g, b) => builder.write_param(Param::Rgb(48, *r, *g, *b)),
             DefaultBg => builder.write_code(49),
+
+            ByteUl(n) => builder.write_param(Param::Byte(58, *n)),
+            RgbUl(r, g, b) => builder.write_param(Param::Rgb(58, *r, *g, *b)),
+            DefaultUl => builder.write_code(59),
         }
     }
 }
@@ -228,33 +392,33 @@ impl DiscreteSGR for Color {
 /// This means it doesn't exist in terms of a [`SGRString`](crate::SGRString),
 /// though it can be used in conjunction with one
 #[allow(clippy::module_name_repetitions)]
-pub trait DiscreteSGR: Sized + Display + EasySGR {
+pub trait DiscreteSGR: Sized + Display {
     /// Writes a set of SGR codes to the given [`SGRWriter`]
     ///
     /// Writing is not an IO operation, instead writing
-    /// pushes codes to the [`SGRBuilder`]'s buffer
-    fn write(&self, writer: &mut SGRBuilder);
-    /// Writes an SGR sequence to the given [`Formatter`](std::fmt::Formatter)
+    /// pushes codes to the [`CapableBuilder`]'s buffer
+    fn write(&self, builder: &mut impl CapableBuilder);
+    /// Writes an SGR sequence to the given [`Formatter`](core::fmt::Formatter)
     ///
     /// # Errors
     ///
-    /// Return an error if writing to the [`Formatter`](std::fmt::Formatter) fails
+    /// Return an error if writing to the [`Formatter`](core::fmt::Formatter) fails
     #[inline]
     #[cfg(not(feature = "partial"))]
-    fn standard_display(&self, f: &mut impl std::fmt::Write) -> std::fmt::Result {
+    fn standard_display(&self, f: &mut impl core::fmt::Write) -> core::fmt::Result {
         SGRWriter::from(f).inline_sgr(self)
     }
-    /// Writes an SGR sequence to the given [`Formatter`](std::fmt::Formatter)
+    /// Writes an SGR sequence to the given [`Formatter`](core::fmt::Formatter)
     ///
     /// Uses [`SGRWriter::partial_sgr`], so the sequence end & escape strings
     /// are not written
     ///
     /// # Errors
     ///
-    /// Return an error if writing to the [`Formatter`](std::fmt::Formatter) fails
+    /// Return an error if writing to the [`Formatter`](core::fmt::Formatter) fails
     #[inline]
     #[cfg(feature = "partial")]
-    fn standard_display(&self, f: &mut impl std::fmt::Write) -> std::fmt::Result {
+    fn standard_display(&self, f: &mut impl core::fmt::Write) -> core::fmt::Result {
         SGRWriter::from(f).partial_sgr(self)
     }
 }
@@ -1,6 +1,14 @@
-use std::fmt::{Debug, Display};
+use core::fmt::{Alignment, Debug, Display};
 
-use crate::{Color, SGRBuilder, SGRWriter, Style};
+use alloc::{borrow::Cow, string::String, vec::Vec};
+
+use crate::{
+    conversion::Layer,
+    style::{StyleChange, STYLE_CODES},
+    width::{display_width, truncate},
+    CapableBuilder, CleanKind, Color, ColorKind, FontKind, IdeogramKind, PadKind, Param, SGRWriter,
+    Style, StyleKind, UnderlineKind,
+};
 
 /// A String encapsulating the usage of SGR codes
 ///
@@ -10,6 +18,10 @@ use crate::{Color, SGRBuilder, SGRWriter, Style};
 ///
 /// Writing is done through the use of the [`writing`](crate::writing) module
 ///
+/// When formatted, the width, fill, alignment & precision of the
+/// [`Formatter`](core::fmt::Formatter) are applied to the text's display width,
+/// with [`SGRString::pad`] deciding whether padding is placed within the SGR codes
+///
 /// # Examples
 ///
 ///```rust
@@ -21,7 +33,7 @@ use crate::{Color, SGRBuilder, SGRWriter, Style};
 ///string.foreground = ColorKind::Red;
 ///println!("{string}");
 ///```
-#[derive(Default, Debug, Clone)]
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
 pub struct SGRString {
     /// The actual text
     pub text: String,
@@ -29,6 +41,10 @@ pub struct SGRString {
     ///
     /// By default [`CleanKind::None`], meaning nothing is done
     pub clean: CleanKind,
+    /// Where padding is placed when a width is specified while formatting
+    ///
+    /// By default [`PadKind::Inside`], meaning the padding is styled
+    pub pad: PadKind,
 
     /// Any custom codes added
     ///
@@ -53,10 +69,19 @@ pub struct SGRString {
     /// Not to be confused with [`ColorKind::Default`], where the default SGR
     /// code for the background is applied.
     pub background: ColorKind,
+    /// The color of the underline
+    ///
+    /// By default [`ColorKind::None`], meaning nothing is applied.
+    /// Not to be confused with [`ColorKind::Default`], where the default SGR
+    /// code for the underline color is applied.
+    ///
+    /// Written as `58;5;<n>` or `58;2;<r>;<g>;<b>`, as there are no
+    /// standard underline color codes
+    pub underline_color: ColorKind,
 
     /// Determines whether the clear code `0` is to be applied to the beginning
     ///
-    /// Not be confused with [`SGRString.clean`], this effects [`SGRString::place_all`]
+    /// Not be confused with [`SGRString::clean`], this effects [`SGRString::place_all`]
     pub reset: bool,
     /// Refer to [`StyleKind`]
     pub bold: StyleKind,
@@ -66,6 +91,10 @@ pub struct SGRString {
     pub italic: StyleKind,
     /// Refer to [`StyleKind`]
     pub underline: StyleKind,
+    /// The style of the underline, refer to [`UnderlineKind`]
+    ///
+    /// Is cleaned by the same code as [`SGRString::underline`], `24`
+    pub underline_style: UnderlineKind,
     /// Refer to [`StyleKind`]
     pub blinking: StyleKind,
     /// Refer to [`StyleKind`]
@@ -74,12 +103,32 @@ pub struct SGRString {
     pub hidden: StyleKind,
     /// Refer to [`StyleKind`]
     pub strikethrough: StyleKind,
+    /// Refer to [`StyleKind`]
+    ///
+    /// Is cleaned by the same code as [`SGRString::underline`], `24`
+    pub doubly_underlined: StyleKind,
+    /// Refer to [`StyleKind`]
+    pub proportional_spacing: StyleKind,
+    /// Refer to [`StyleKind`]
+    pub framed: StyleKind,
+    /// Refer to [`StyleKind`]
+    pub encircled: StyleKind,
+    /// Refer to [`StyleKind`]
+    pub overline: StyleKind,
+    /// Refer to [`StyleKind`]
+    pub superscript: StyleKind,
+    /// Refer to [`StyleKind`]
+    pub subscript: StyleKind,
+    /// The font used, refer to [`FontKind`]
+    pub font: FontKind,
+    /// The ideogram style, refer to [`IdeogramKind`]
+    pub ideogram: IdeogramKind,
 }
 impl SGRString {
-    /// Writes all contained SGR codes to the given [`SGRBuilder`]
+    /// Writes all contained SGR codes to the given [`CapableBuilder`]
     ///
     /// Does not perform any IO operations
-    pub fn place_all(&self, builder: &mut SGRBuilder) {
+    pub fn place_all(&self, builder: &mut impl CapableBuilder) {
         if self.reset {
             builder.write_code(0);
         }
@@ -90,7 +139,7 @@ impl SGRString {
     /// Writes contained SGR color codes to the given [`SGRWriter`]
     ///
     /// Does not perform any IO operations
-    pub fn place_colors(&self, builder: &mut SGRBuilder) {
+    pub fn place_colors(&self, builder: &mut impl CapableBuilder) {
         use ColorKind::*;
         match self.foreground {
             Black => builder.write_code(30),
@@ -101,11 +150,19 @@ impl SGRString {
             Magenta => builder.write_code(35),
             Cyan => builder.write_code(36),
             White => builder.write_code(37),
-            Byte(n) => builder.write_codes(&[38, 5, n]),
-            Rgb(r, g, b) => builder.write_codes(&[38, 2, r, g, b]),
+            BrightBlack => builder.write_code(90),
+            BrightRed => builder.write_code(91),
+            BrightGreen => builder.write_code(92),
+            BrightYellow => builder.write_code(93),
+            BrightBlue => builder.write_code(94),
+            BrightMagenta => builder.write_code(95),
+            BrightCyan => builder.write_code(96),
+            BrightWhite => builder.write_code(97),
+            Byte(n) => builder.write_param(Param::Byte(38, n)),
+            Rgb(r, g, b) => builder.write_param(Param::Rgb(38, r, g, b)),
             Default => builder.write_code(39),
             ColorKind::None => (),
-        };
+        }
         match self.background {
             Black => builder.write_code(40),
             Red => builder.write_code(41),
@@ -115,38 +172,52 @@ impl SGRString {
             Magenta => builder.write_code(45),
             Cyan => builder.write_code(46),
             White => builder.write_code(47),
-            Byte(n) => builder.write_codes(&[48, 5, n]),
-            Rgb(r, g, b) => builder.write_codes(&[48, 2, r, g, b]),
+            BrightBlack => builder.write_code(100),
+            BrightRed => builder.write_code(101),
+            BrightGreen => builder.write_code(102),
+            BrightYellow => builder.write_code(103),
+            BrightBlue => builder.write_code(104),
+            BrightMagenta => builder.write_code(105),
+            BrightCyan => builder.write_code(106),
+            BrightWhite => builder.write_code(107),
+            Byte(n) => builder.write_param(Param::Byte(48, n)),
+            Rgb(r, g, b) => builder.write_param(Param::Rgb(48, r, g, b)),
             Default => builder.write_code(49),
             ColorKind::None => (),
-        };
+        }
+        match self.underline_color {
+            Byte(n) => builder.write_param(Param::Byte(58, n)),
+            Rgb(r, g, b) => builder.write_param(Param::Rgb(58, r, g, b)),
+            Default => builder.write_code(59),
+            ColorKind::None => (),
+            ref kind => builder.write_param(Param::Byte(58, kind.index().unwrap_or_default())),
+        }
     }
     /// Writes SGR style codes to the given [`SGRWriter`]
     ///
     /// Does not perform any IO operations
-    pub fn place_styles(&self, builder: &mut SGRBuilder) {
-        use StyleKind::*;
-        for (kind, place, not) in [
-            (&self.bold, 1, 22),
-            (&self.dim, 2, 22),
-            (&self.italic, 3, 23),
-            (&self.underline, 4, 24),
-            (&self.blinking, 5, 25),
-            (&self.inverse, 7, 27),
-            (&self.hidden, 8, 28),
-            (&self.strikethrough, 9, 29),
-        ] {
+    pub fn place_styles(&self, builder: &mut impl CapableBuilder) {
+        for (kind, (place, not)) in self.styles().into_iter().zip(STYLE_CODES) {
             match kind {
-                None => (),
-                Place => builder.write_code(place),
-                Clean => builder.write_code(not),
+                StyleKind::None => (),
+                StyleKind::Place => builder.write_code(place),
+                StyleKind::Clean => builder.write_code(not),
             }
         }
+        if let Some(sub) = self.underline_style.sub() {
+            builder.write_sub(4, sub);
+        }
+        if let Some(code) = self.font.code() {
+            builder.write_code(code);
+        }
+        if let Some(code) = self.ideogram.code() {
+            builder.write_code(code);
+        }
     }
     /// Writes custom SGR codes to the given [`SGRWriter`]
     ///
     /// Does not perform any IO operations
-    pub fn place_custom(&self, builder: &mut SGRBuilder) {
+    pub fn place_custom(&self, builder: &mut impl CapableBuilder) {
         builder.write_codes(&self.custom_places);
     }
     /// Writes contained SGR codes to the given [`SGRWriter`]
@@ -154,7 +225,7 @@ impl SGRString {
     /// Reverses the effects of [`SGRString::place_all`]
     ///
     /// Does not perform any IO operations
-    pub fn clean_all(&self, builder: &mut SGRBuilder) {
+    pub fn clean_all(&self, builder: &mut impl CapableBuilder) {
         match self.clean {
             CleanKind::Reset => builder.write_code(0),
             CleanKind::Reverse => {
@@ -170,43 +241,212 @@ impl SGRString {
     /// Reverses the effects of [`SGRString::place_colors`]
     ///
     /// Does not perform any IO operations
-    pub fn clean_colors(&self, builder: &mut SGRBuilder) {
+    pub fn clean_colors(&self, builder: &mut impl CapableBuilder) {
         if self.foreground != ColorKind::None {
             builder.write_code(39);
         }
         if self.background != ColorKind::None {
             builder.write_code(49);
         }
+        if self.underline_color != ColorKind::None {
+            builder.write_code(59);
+        }
     }
     /// Writes SGR style codes to the given [`SGRWriter`]
     ///
     /// Reverses the effects of [`SGRString::place_styles`]
     ///
     /// Does not perform any IO operations
-    pub fn clean_styles(&self, builder: &mut SGRBuilder) {
-        for (kind, place, not) in [
-            (&self.bold, 22, 1),
-            (&self.dim, 22, 2),
-            (&self.italic, 23, 3),
-            (&self.underline, 24, 4),
-            (&self.blinking, 25, 5),
-            (&self.inverse, 27, 7),
-            (&self.hidden, 28, 8),
-            (&self.strikethrough, 29, 9),
-        ] {
+    pub fn clean_styles(&self, builder: &mut impl CapableBuilder) {
+        for (kind, (place, not)) in self.styles().into_iter().zip(STYLE_CODES) {
             match kind {
                 StyleKind::None => (),
-                StyleKind::Place => builder.write_code(place),
-                StyleKind::Clean => builder.write_code(not),
+                StyleKind::Place => builder.write_code(not),
+                StyleKind::Clean => builder.write_code(place),
+            }
+        }
+        if self.underline_style != UnderlineKind::None {
+            builder.write_code(24);
+        }
+        if !matches!(self.font, FontKind::None | FontKind::Primary) {
+            builder.write_code(10);
+        }
+        if !matches!(self.ideogram, IdeogramKind::None | IdeogramKind::Clean) {
+            builder.write_code(65);
+        }
+    }
+    /// Writes contained SGR codes to the given [`SGRWriter`]
+    ///
+    /// Reverses the effects of [`SGRString::place_all`],
+    /// restoring the state of the enclosing `outer` [`SGRString`]
+    ///
+    /// Codes such as `22` undo more than one attribute,
+    /// so the attributes of `outer` they undo are placed again.
+    ///
+    ///```rust
+    /// use easy_sgr::{CleanKind, Color::*, EasySGR, Param::Code, SGRBuilder, Style::*};
+    ///
+    /// let outer = Dim.color(RedFg);
+    /// let inner = Bold.color(BlueFg).clean(CleanKind::Reverse);
+    ///
+    /// let mut builder = SGRBuilder::default();
+    /// inner.clean_all_within(&outer, &mut builder);
+    /// assert_eq!(builder.0, [Code(31), Code(22), Code(2)]);
+    ///```
+    ///
+    /// Does not perform any IO operations
+    pub fn clean_all_within(&self, outer: &Self, builder: &mut impl CapableBuilder) {
+        match self.clean {
+            CleanKind::Reset => {
+                builder.write_code(0);
+                outer.place_colors(builder);
+                outer.place_styles(builder);
+            }
+            CleanKind::Reverse => {
+                self.clean_colors_within(outer, builder);
+                self.clean_styles_within(outer, builder);
             }
+            CleanKind::None => (),
         }
+        self.clean_custom(builder);
+    }
+    /// Writes SGR color codes to the given [`SGRWriter`]
+    ///
+    /// Reverses the effects of [`SGRString::place_colors`],
+    /// placing the colors of `outer` instead of the defaults where it has them
+    ///
+    /// Does not perform any IO operations
+    pub fn clean_colors_within(&self, outer: &Self, builder: &mut impl CapableBuilder) {
+        let mut restore = Self::default();
+        for (inner, outer, restore, not) in [
+            (
+                &self.foreground,
+                &outer.foreground,
+                &mut restore.foreground,
+                39,
+            ),
+            (
+                &self.background,
+                &outer.background,
+                &mut restore.background,
+                49,
+            ),
+            (
+                &self.underline_color,
+                &outer.underline_color,
+                &mut restore.underline_color,
+                59,
+            ),
+        ] {
+            if *inner == ColorKind::None || inner == outer {
+                continue;
+            }
+            match outer {
+                ColorKind::None => builder.write_code(not),
+                kind => *restore = kind.clone(),
+            }
+        }
+        restore.place_colors(builder);
+    }
+    /// Writes SGR style codes to the given [`SGRWriter`]
+    ///
+    /// Reverses the effects of [`SGRString::place_styles`],
+    /// placing the styles of `outer` that were undone again
+    ///
+    /// Does not perform any IO operations
+    pub fn clean_styles_within(&self, outer: &Self, builder: &mut impl CapableBuilder) {
+        let inner = self.style_params();
+        let outer = outer.style_params();
+        let mut resets = Vec::new();
+        for ((touched, place, not), (_, outer_place, _)) in inner.iter().zip(&outer) {
+            if *touched && place.is_some() && outer_place.is_none() && !resets.contains(not) {
+                resets.push(*not);
+            }
+        }
+        builder.write_codes(&resets);
+        for ((touched, place, not), (_, outer_place, _)) in inner.iter().zip(&outer) {
+            if let Some(outer_place) = outer_place {
+                if resets.contains(not) || (*touched && *place != Some(*outer_place)) {
+                    builder.write_param(*outer_place);
+                }
+            }
+        }
+    }
+    /// Lists every style as whether it is set, the [`Param`] placing it
+    /// and the code that undoes it
+    fn style_params(&self) -> Vec<(bool, Option<Param>, u8)> {
+        let underline_style = self.underline_style.sub().map(|sub| Param::Sub(4, sub));
+        let font = match self.font {
+            FontKind::None => (false, None, 10),
+            FontKind::Primary => (true, None, 10),
+            ref font => (true, font.code().map(Param::Code), 10),
+        };
+        let ideogram = match self.ideogram {
+            IdeogramKind::None => (false, None, 65),
+            IdeogramKind::Clean => (true, None, 65),
+            ref ideogram => (true, ideogram.code().map(Param::Code), 65),
+        };
+        self.styles()
+            .into_iter()
+            .zip(STYLE_CODES)
+            .map(|(kind, (place, not))| match kind {
+                StyleKind::None => (false, None, not),
+                StyleKind::Place => (true, Some(Param::Code(place)), not),
+                StyleKind::Clean => (true, None, not),
+            })
+            .chain([
+                (underline_style.is_some(), underline_style, 24),
+                font,
+                ideogram,
+            ])
+            .collect()
+    }
+    /// Lists every attribute held as a [`StyleKind`], in the order of [`STYLE_CODES`]
+    const fn styles(&self) -> [&StyleKind; STYLE_CODES.len()] {
+        [
+            &self.bold,
+            &self.dim,
+            &self.italic,
+            &self.underline,
+            &self.blinking,
+            &self.inverse,
+            &self.hidden,
+            &self.strikethrough,
+            &self.doubly_underlined,
+            &self.proportional_spacing,
+            &self.framed,
+            &self.encircled,
+            &self.overline,
+            &self.superscript,
+            &self.subscript,
+        ]
+    }
+    /// Lists every attribute held as a [`StyleKind`], in the order of [`STYLE_CODES`]
+    const fn styles_mut(&mut self) -> [&mut StyleKind; STYLE_CODES.len()] {
+        [
+            &mut self.bold,
+            &mut self.dim,
+            &mut self.italic,
+            &mut self.underline,
+            &mut self.blinking,
+            &mut self.inverse,
+            &mut self.hidden,
+            &mut self.strikethrough,
+            &mut self.doubly_underlined,
+            &mut self.proportional_spacing,
+            &mut self.framed,
+            &mut self.encircled,
+            &mut self.overline,
+            &mut self.superscript,
+            &mut self.subscript,
+        ]
     }
     /// Writes SGR codes to the given [`SGRWriter`]
     ///
     /// Reverses the effects of [`SGRString::place_custom`]
     ///
     /// Does not perform any IO operations
-    pub fn clean_custom(&self, builder: &mut SGRBuilder) {
+    pub fn clean_custom(&self, builder: &mut impl CapableBuilder) {
         builder.write_codes(&self.custom_cleans);
     }
 }
@@ -244,59 +484,106 @@ impl From<&String> for SGRString {
         }
     }
 }
+impl From<SGRString> for Cow<'_, SGRString> {
+    fn from(value: SGRString) -> Self {
+        Cow::Owned(value)
+    }
+}
+impl<'a> From<&'a SGRString> for Cow<'a, SGRString> {
+    fn from(value: &'a SGRString) -> Self {
+        Cow::Borrowed(value)
+    }
+}
 impl Display for SGRString {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let truncated = f
+            .precision()
+            .map(|precision| truncate(&self.text, precision));
+        let text = truncated.as_deref().unwrap_or(&self.text);
+        let padding = f.width().unwrap_or(0).saturating_sub(display_width(text));
+        let (before, after) = match f.align() {
+            Some(Alignment::Right) => (padding, 0),
+            Some(Alignment::Center) => (padding / 2, padding - padding / 2),
+            Some(Alignment::Left) | None => (0, padding),
+        };
+        let mut fill = [0; 4];
+        let fill = &*f.fill().encode_utf8(&mut fill);
+
         let mut fmt = SGRWriter::from(f);
-        fmt.place_sgr(self)?;
-        fmt.write_inner(&self.text)?;
-        fmt.clean_sgr(self)
+        let pad = |fmt: &mut SGRWriter<_>, n| (0..n).try_for_each(|_| fmt.write_inner(fill));
+        match self.pad {
+            PadKind::Inside => {
+                fmt.place_sgr(self)?;
+                pad(&mut fmt, before)?;
+                fmt.write_inner(text)?;
+                pad(&mut fmt, after)?;
+                fmt.clean_sgr(self)
+            }
+            PadKind::Outside => {
+                pad(&mut fmt, before)?;
+                fmt.place_sgr(self)?;
+                fmt.write_inner(text)?;
+                fmt.clean_sgr(self)?;
+                pad(&mut fmt, after)
+            }
+        }
     }
 }
-/// Component of [`SGRString`]; the type of clean
-#[derive(Debug, Clone, Default, PartialEq, Eq)]
-pub enum CleanKind {
-    /// Does nothing
-    #[default]
-    None,
-    /// Resets all by writing `\x1b[0m`
-    Reset,
-    /// Undoes the effects of the [`SGRString::place_all`].
-    Reverse,
+/// Text styled by an [`SGRString`], without the text being copied into it
+///
+/// Holds any [`Display`] type, writing the style's place codes,
+/// the inner value & then the style's clean codes when formatted.
+/// The text of the style is ignored.
+///
+/// The width, fill, alignment & precision of the [`Formatter`](core::fmt::Formatter)
+/// are passed on to the inner value, so any padding is always styled
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{CleanKind, Color::*, EasySGR, Painted, Style::*};
+///
+///let value = 42;
+///assert_eq!(Bold.paint(&value).to_string(), "\x1b[1m42");
+///
+///let style = RedFg.clean(CleanKind::Reverse);
+///let name = String::from("name");
+///assert_eq!(
+///    format!("{:<6}|", Painted::new(&style, name.as_str())),
+///    "\x1b[31mname  \x1b[39m|"
+///);
+///```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Painted<'a, T: Display> {
+    /// The style placed before & cleaned after the inner value
+    pub style: Cow<'a, SGRString>,
+    /// The value being styled
+    pub inner: T,
 }
-/// Component of [`SGRString`]; the type of a style
-#[derive(Debug, Clone, Default, PartialEq, Eq)]
-pub enum StyleKind {
-    /// Do nothing
-    #[default]
-    None,
-    /// Apply the style
-    Place,
-    /// Apply what undoes the style
-    ///
-    /// The equivalent in [`Style`] are variants prefixed with `Not`
-    Clean,
+impl<'a, T: Display> Painted<'a, T> {
+    /// Creates a new [`Painted`] from an owned or borrowed [`SGRString`]
+    pub fn new(style: impl Into<Cow<'a, SGRString>>, inner: T) -> Self {
+        Self {
+            style: style.into(),
+            inner,
+        }
+    }
+    /// Creates a new [`Painted`] with the given style,
+    /// leaving the value unstyled when there is none
+    pub fn or_plain(style: Option<&'a SGRString>, inner: T) -> Self {
+        match style {
+            Some(style) => Self::new(style, inner),
+            None => Self::new(SGRString::default(), inner),
+        }
+    }
 }
-/// Component of [`SGRString`]; the type of color
-///
-/// Used for both foreground and background
-#[derive(Debug, Clone, Default, PartialEq, Eq)]
-#[allow(missing_docs)]
-pub enum ColorKind {
-    /// Does nothing
-    #[default]
-    None,
-    Black,
-    Red,
-    Green,
-    Yellow,
-    Blue,
-    Magenta,
-    Cyan,
-    White,
-    Byte(u8),
-    Rgb(u8, u8, u8),
-    /// Applies the default `SGR` color
-    Default,
+impl<T: Display> Display for Painted<'_, T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut fmt = SGRWriter::from(f);
+        fmt.place_sgr(&self.style)?;
+        self.inner.fmt(fmt.writer.0)?;
+        fmt.clean_sgr(&self.style)
+    }
 }
 impl<I: Into<SGRString>> EasySGR for I {}
 /// Allows for chaining SGR sequence types
@@ -328,33 +615,26 @@ pub trait EasySGR: Into<SGRString> {
             ..self.into()
         }
     }
+    /// Styles the given value without allocating its text
+    ///
+    /// Equivalent to [`EasySGR::text`], though the value is
+    /// only written when the returned [`Painted`] is formatted
+    #[must_use]
+    #[inline]
+    fn paint<T: Display>(self, inner: T) -> Painted<'static, T> {
+        Painted::new(self.into(), inner)
+    }
     /// Adds a style to the returned [`SGRString`]
     #[must_use]
     #[inline]
     fn style(self, style: impl Into<Style>) -> SGRString {
-        use Style::*;
-        use StyleKind::*;
-
         let mut this = self.into();
-        match style.into() {
-            Reset => this.reset = true,
-            Bold => this.bold = Place,
-            Dim => this.dim = Place,
-            Italic => this.italic = Place,
-            Underline => this.underline = Place,
-            Blinking => this.blinking = Place,
-            Inverse => this.inverse = Place,
-            Hidden => this.hidden = Place,
-            Strikethrough => this.strikethrough = Place,
-
-            NotBold => this.bold = Clean,
-            NotDim => this.dim = Clean,
-            NotItalic => this.italic = Clean,
-            NotUnderline => this.underline = Clean,
-            NotBlinking => this.blinking = Clean,
-            NotInverse => this.inverse = Clean,
-            NotHidden => this.hidden = Clean,
-            NotStrikethrough => this.strikethrough = Clean,
+        match style.into().change() {
+            StyleChange::Reset => this.reset = true,
+            StyleChange::Style(i, kind) => *this.styles_mut()[i] = kind,
+            StyleChange::Underline(kind) => this.underline_style = kind,
+            StyleChange::Font(kind) => this.font = kind,
+            StyleChange::Ideogram(kind) => this.ideogram = kind,
         }
         this
     }
@@ -362,35 +642,13 @@ pub trait EasySGR: Into<SGRString> {
     #[must_use]
     #[inline]
     fn color(self, color: impl Into<Color>) -> SGRString {
-        use {Color::*, ColorKind::*};
-
         let mut this = self.into();
-
-        (this.foreground, this.background) = match color.into() {
-            BlackFg => (Black, this.background),
-            RedFg => (Red, this.background),
-            GreenFg => (Green, this.background),
-            YellowFg => (Yellow, this.background),
-            BlueFg => (Blue, this.background),
-            MagentaFg => (Magenta, this.background),
-            CyanFg => (Cyan, this.background),
-            WhiteFg => (White, this.background),
-            ByteFg(n) => (Byte(n), this.background),
-            RgbFg(r, g, b) => (Rgb(r, g, b), this.background),
-            DefaultFg => (Default, this.background),
-
-            BlackBg => (this.foreground, Black),
-            RedBg => (this.foreground, Red),
-            GreenBg => (this.foreground, Green),
-            YellowBg => (this.foreground, Yellow),
-            BlueBg => (this.foreground, Blue),
-            MagentaBg => (this.foreground, Magenta),
-            CyanBg => (this.foreground, Cyan),
-            WhiteBg => (this.foreground, White),
-            ByteBg(n) => (this.foreground, Byte(n)),
-            RgbBg(r, g, b) => (this.foreground, Rgb(r, g, b)),
-            DefaultBg => (this.foreground, Default),
-        };
+        let (kind, layer) = color.into().split();
+        match layer {
+            Layer::Foreground => this.foreground = kind,
+            Layer::Background => this.background = kind,
+            Layer::Underline => this.underline_color = kind,
+        }
         this
     }
     /// Adds a custom code to the returned [`SGRString`]
@@ -411,6 +669,14 @@ pub trait EasySGR: Into<SGRString> {
         this.clean = clean.into();
         this
     }
+    /// Sets the [`PadKind`] variant of the returned [`SGRString`]
+    #[must_use]
+    #[inline]
+    fn pad(self, pad: impl Into<PadKind>) -> SGRString {
+        let mut this = self.into();
+        this.pad = pad.into();
+        this
+    }
     /// Adds a custom code to be written before the returned [`SGRString`]'s text
     #[must_use]
     #[inline]
@@ -0,0 +1,293 @@
+use core::{error::Error, fmt::Display, num::ParseIntError};
+
+use alloc::{string::String, vec::Vec};
+
+use crate::{
+    conversion::basic_kind, parse_hex, parsing::apply_params, ColorKind, Param, SGRString,
+    StyleKind,
+};
+
+/// The names of the basic colors, in order of their index
+const COLOR_NAMES: [&str; 8] = [
+    "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+];
+
+impl SGRString {
+    /// Parses the keywords of the `sgr` macros, as written within `{[...]}`,
+    /// into an [`SGRString`] with no text
+    ///
+    /// Keywords are separated by whitespace & applied the same way as [`SGRParser`](crate::SGRParser)
+    /// applies codes, no keywords at all being read as a reset. Each is either:
+    ///
+    /// - A style, i.e. `bold` or `curly-underline`, undone when prefixed by `!`.
+    ///   `!bold` & `!dim` each undo only the named style, though both are written as `22`
+    /// - A named color, i.e. `red` or `bright-red`
+    /// - A color of the 256 color palette, i.e. `208` or `#d0`
+    /// - An rgb color, i.e. `15,115,215` or `#0f73d7`
+    ///
+    /// Colors are foregrounds unless prefixed by `on-` for a background,
+    /// or `ul-` for an underline color
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a keyword is not recognised or is an invalid color
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{ColorKind, SGRString, StyleKind};
+    ///
+    ///let sgr = SGRString::from_keywords("italic red on-#0f73d7").unwrap();
+    ///assert_eq!(sgr.italic, StyleKind::Place);
+    ///assert_eq!(sgr.foreground, ColorKind::Red);
+    ///assert_eq!(sgr.background, ColorKind::Rgb(15, 115, 215));
+    ///
+    ///let sgr = SGRString::from_keywords("!bold ul-208").unwrap();
+    ///assert_eq!(sgr.bold, StyleKind::Clean);
+    ///assert_eq!(sgr.underline_color, ColorKind::Byte(208));
+    ///
+    ///assert!(SGRString::from_keywords("bold wavy").is_err());
+    ///```
+    pub fn from_keywords(s: &str) -> Result<Self, ParseKeywordsError> {
+        let mut sgr = Self::default();
+        if s.trim().is_empty() {
+            apply_params(&mut sgr, &[Param::Code(0)]);
+        }
+        for word in s.split_whitespace() {
+            match word {
+                // `22` undoes both, though like the `sgr` macros only the named style is cleaned
+                "!bold" => sgr.bold = StyleKind::Clean,
+                "!dim" => sgr.dim = StyleKind::Clean,
+                word => apply_params(&mut sgr, &[keyword(word)?]),
+            }
+        }
+        Ok(sgr)
+    }
+    /// Parses a color as written in git's config, such as `color.diff.old`,
+    /// into an [`SGRString`] with no text
+    ///
+    /// Words are separated by whitespace & read regardless of case. Each is either:
+    ///
+    /// - A color, the first being the foreground & the second the background
+    ///     - `normal` or `-1`, leaving the color unchanged
+    ///     - `default`
+    ///     - `black`, `red`, `green`, `yellow`, `blue`, `magenta`, `cyan` or `white`,
+    ///       optionally prefixed by `bright`
+    ///     - A number from `0`-`255`, the first eight being the basic colors
+    ///     - A hex color, see [`parse_hex`]
+    /// - An attribute, undone when prefixed by `no` or `no-`
+    ///     - `bold`, `dim`, `italic`, `ul` or `underline`, `blink`, `reverse` or `strike`
+    /// - `reset`, resetting all attributes before the others are placed
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a word is not recognised, or more than two colors are given
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{ColorKind, SGRString, StyleKind};
+    ///
+    ///let sgr = SGRString::from_git_color("red bold ul").unwrap();
+    ///assert_eq!(sgr.foreground, ColorKind::Red);
+    ///assert_eq!(sgr.bold, StyleKind::Place);
+    ///assert_eq!(sgr.underline, StyleKind::Place);
+    ///
+    ///let sgr = SGRString::from_git_color("#ff00aa black nobold").unwrap();
+    ///assert_eq!(sgr.foreground, ColorKind::Rgb(255, 0, 170));
+    ///assert_eq!(sgr.background, ColorKind::Black);
+    ///assert_eq!(sgr.bold, StyleKind::Clean);
+    ///
+    ///assert!(SGRString::from_git_color("red blue green").is_err());
+    ///```
+    pub fn from_git_color(s: &str) -> Result<Self, ParseGitColorError> {
+        let mut sgr = Self::default();
+        let mut colors = 0;
+        for word in s.split_whitespace() {
+            if let Some(color) = git_color(word) {
+                match colors {
+                    0 => sgr.foreground = color,
+                    1 => sgr.background = color,
+                    _ => return Err(ParseGitColorError::Colors(String::from(word))),
+                }
+                colors += 1;
+                continue;
+            }
+            let lower = word.to_ascii_lowercase();
+            if lower == "reset" {
+                sgr.reset = true;
+                continue;
+            }
+            let (attr, kind) = lower
+                .strip_prefix("no")
+                .map_or((lower.as_str(), StyleKind::Place), |attr| {
+                    (attr.strip_prefix('-').unwrap_or(attr), StyleKind::Clean)
+                });
+            let field = match attr {
+                "bold" => &mut sgr.bold,
+                "dim" => &mut sgr.dim,
+                "italic" => &mut sgr.italic,
+                "ul" | "underline" => &mut sgr.underline,
+                "blink" => &mut sgr.blinking,
+                "reverse" => &mut sgr.inverse,
+                "strike" => &mut sgr.strikethrough,
+                _ => return Err(ParseGitColorError::Word(String::from(word))),
+            };
+            *field = kind;
+        }
+        Ok(sgr)
+    }
+}
+/// Parses a single keyword of the `sgr` macros, see [`SGRString::from_keywords`]
+fn keyword(word: &str) -> Result<Param, ParseKeywordsError> {
+    let code = match word {
+        // styles
+        "reset" => 0,
+        "bold" => 1,
+        "dim" => 2,
+        "italic" => 3,
+        "underline" => 4,
+        "blink" => 5,
+        "inverse" => 7,
+        "hide" => 8,
+        "strike" => 9,
+        // undo styles
+        "!italic" => 23,
+        "!underline" => 24,
+        "!blink" => 25,
+        "!inverse" => 27,
+        "!hide" => 28,
+        "!strike" => 29,
+        // underline styles
+        "double-underline" => return Ok(Param::Sub(4, 2)),
+        "curly-underline" => return Ok(Param::Sub(4, 3)),
+        "dotted-underline" => return Ok(Param::Sub(4, 4)),
+        "dashed-underline" => return Ok(Param::Sub(4, 5)),
+        // default colors
+        "default" => 39,
+        "on-default" => 49,
+        "ul-default" => 59,
+        _ => return named_color(word).map_or_else(|| complex_color(word), Ok),
+    };
+    Ok(Param::Code(code))
+}
+/// Parses a named color keyword, i.e. `on-bright-red`
+fn named_color(word: &str) -> Option<Param> {
+    let (base, name) = word
+        .strip_prefix("on-")
+        .map_or((30, word), |name| (40, name));
+    let (base, name) = name
+        .strip_prefix("bright-")
+        .map_or((base, name), |name| (base + 60, name));
+    (0..)
+        .zip(COLOR_NAMES)
+        .find(|&(_, color)| color == name)
+        .map(|(i, _)| Param::Code(base + i))
+}
+/// Parses a color keyword given as numbers, i.e. `on-15`, `15,115,215` or `ul-#0f73d7`
+fn complex_color(word: &str) -> Result<Param, ParseKeywordsError> {
+    let (code, color) = match (word.strip_prefix("on-"), word.strip_prefix("ul-")) {
+        (Some(color), _) => (48, color),
+        (_, Some(color)) => (58, color),
+        _ => (38, word),
+    };
+    let int = |e| ParseKeywordsError::Int(String::from(word), e);
+    let len = || ParseKeywordsError::ColorLen(String::from(word));
+    if let Some(hex) = color.strip_prefix('#') {
+        let hex_at = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).map_err(int);
+        return match hex.len() {
+            2 if hex.is_ascii() => Ok(Param::Byte(code, hex_at(0)?)),
+            6 if hex.is_ascii() => Ok(Param::Rgb(code, hex_at(0)?, hex_at(2)?, hex_at(4)?)),
+            _ => Err(len()),
+        };
+    }
+    if !color.starts_with(|ch: char| ch.is_ascii_digit()) {
+        return Err(ParseKeywordsError::Keyword(String::from(word)));
+    }
+    let parts = color
+        .split(',')
+        .map(str::parse)
+        .collect::<Result<Vec<u8>, _>>()
+        .map_err(int)?;
+    match parts[..] {
+        [n] => Ok(Param::Byte(code, n)),
+        [r, g, b] => Ok(Param::Rgb(code, r, g, b)),
+        _ => Err(len()),
+    }
+}
+/// Parses a single color of git's color config, see [`SGRString::from_git_color`]
+///
+/// Returns `None` if the word is not a color
+fn git_color(word: &str) -> Option<ColorKind> {
+    if word.starts_with('#') {
+        return parse_hex(word)
+            .ok()
+            .map(|(r, g, b)| ColorKind::Rgb(r, g, b));
+    }
+    if let Ok(n) = word.parse::<u8>() {
+        return Some(if n < 8 {
+            basic_kind(n)
+        } else {
+            ColorKind::Byte(n)
+        });
+    }
+    let word = word.to_ascii_lowercase();
+    match word.as_str() {
+        "normal" | "-1" => return Some(ColorKind::None),
+        "default" => return Some(ColorKind::Default),
+        _ => (),
+    }
+    let (name, offset) = word
+        .strip_prefix("bright")
+        .map_or((word.as_str(), 0), |name| (name, 8));
+    (0..)
+        .zip(COLOR_NAMES)
+        .find(|&(_, color)| color == name)
+        .map(|(i, _)| basic_kind(i + offset))
+}
+/// An error encountered while trying to parse a git color, see [`SGRString::from_git_color`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseGitColorError {
+    /// A word that is neither a color nor an attribute
+    Word(String),
+    /// A color given after both the foreground & background
+    Colors(String),
+}
+impl Display for ParseGitColorError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Word(word) => write!(f, "Invalid git color word: {word}"),
+            Self::Colors(word) => write!(f, "Too many git colors: {word}"),
+        }
+    }
+}
+impl Error for ParseGitColorError {}
+/// An error encountered while trying to parse keywords, see [`SGRString::from_keywords`]
+///
+/// Each variant holds the keyword the error is in
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseKeywordsError {
+    /// A keyword that is not recognised
+    Keyword(String),
+    /// A color without one or three values, or a hex color without two or six digits
+    ColorLen(String),
+    /// A color with a value that is not a valid [`u8`]
+    Int(String, ParseIntError),
+}
+impl Display for ParseKeywordsError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Keyword(word) => write!(f, "Invalid keyword: {word}"),
+            Self::ColorLen(word) => write!(f, "Invalid color length: {word}"),
+            Self::Int(word, e) => write!(f, "{e} in color: {word}"),
+        }
+    }
+}
+impl Error for ParseKeywordsError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Int(_, e) => Some(e),
+            _ => None,
+        }
+    }
+}
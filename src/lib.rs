@@ -31,7 +31,7 @@
 //!
 //! And its usage is very simple:
 //!
-//! ```rust
+//! ```rust,ignore
 //! use easy_sgr::println;
 //!
 //! println!("{[italic red]}This should be italic & red!{[]}");
@@ -70,7 +70,7 @@
 //! ### `EasySGR` trait
 //!
 //! This is similar to the method above but uses the `EasySGR` trait.
-//! This trait is implemented by anything that implements Into\<AnsiString\> including Style and Color.
+//! This trait is implemented by anything that implements `Into<SGRString>` including Style and Color.
 //! Its main purpose is to provide functions for chaining [`SGR`][SGR] codes.
 //!
 //! The example above can be achieved using it as such:
@@ -175,10 +175,29 @@
 //!
 //! This feature exchanges ease of use for verbosity, resulting in more control.
 //!
+//! ### `std` & `alloc`
+//!
+//! `std` is enabled by default, disabling it makes the library `no_std`:
+//!
+//! ```toml
+//! [dependencies]
+//! easy-sgr = { version = "0.1.1", default-features = false, features = ["alloc"] }
+//! ```
+//!
+//! With `alloc` everything except `IoWriter`, `StripWriter` & color support detection
+//! is still available. Without it, only the `discrete` types, `SGRStyle`, `SGRWriter`
+//! & `InlineBuilder` remain, which never allocate.
+//!
 //! ## Structure
 //!
-//! easy-sgr is split into three modules:
+//! easy-sgr is split into fourteen modules:
 //!
+//! - capability
+//!     - Implements `ColorSupport`
+//!     - Detects the colors a terminal supports, used to downgrade colors when writing
+//! - conversion
+//!     - Converts between rgb, 256 & 16 color palettes
+//!     - Parses & formats `#rrggbb` and `#rgb` hex colors
 //! - discrete
 //!     - Contains types that can be used inline of a string literal
 //!     - The types, `Seq`, `Color` & `Style` are all able to function independently
@@ -196,8 +215,36 @@
 //!         - `&str`
 //!         - `String`
 //!         - `&String`
+//!     - `Painted` styles any `Display` value without copying its text
+//! - keywords
+//!     - Implements `SGRString::from_keywords` & `SGRString::from_git_color`
+//!     - Parses styles written as words, such as `italic red on-#0f73d7` or `red bold ul`
+//! - `ls_colors`
+//!     - Implements `LsColors`
+//!     - Parses `LS_COLORS`, styling paths the same way `ls` does
+//! - parsing
+//!     - Implements `SGRParser`, `strip_sgr` & `StripWriter`
+//!     - Reads [`SGR`][SGR] sequences & codes back into `SGRString`s, or removes them
+//! - span
+//!     - Implements `SGRSpan`
+//!     - Nests styled text, restoring the surrounding style instead of resetting it
+//! - state
+//!     - Implements `SGRState`
+//!     - Finds the shortest codes to go from one set of attributes to another
+//! - style
+//!     - Implements `SGRStyle`
+//!     - A `const` constructible style, with escapes computed at compile time
+//! - `style_map`
+//!     - Implements `StyleMap`
+//!     - Parses `key=codes` lists such as `GREP_COLORS` & `GCC_COLORS`
+//! - theme
+//!     - Implements `Theme`
+//!     - Maps semantic roles such as `error` to styles, loadable from text
+//! - width
+//!     - Implements `display_width`
+//!     - Measures text as displayed, skipping [`SGR`][SGR] sequences
 //! - writing
-//!     - Implements `SGRWriter` & `SGRBuilder`
+//!     - Implements `SGRWriter`, `SGRBuilder` & `InlineBuilder`
 //!     - Used by other modules to do writing
 //!
 //! Though no modules really will be seen in usage,
@@ -212,10 +259,11 @@
 //!     - [ ] `graphics`
 //!     - [ ] `writing`
 //! - [x] Macros (`east-sgr-macros`) (`0.1.0`)
-//! - [ ] Add parser?
-//!     - [ ] Add parsing from ansi codes
-//!     - [ ] Add parsing for `SGRString`
+//! - [x] Add parser?
+//!     - [x] Add parsing from ansi codes
+//!     - [x] Add parsing for `SGRString`
 //! - [ ] `EasySGR` implementation that doesn't allocate an `SGRString`
+#![cfg_attr(not(feature = "std"), no_std)]
 #![forbid(unsafe_code)]
 #![deny(
     clippy::all,
@@ -228,6 +276,19 @@
 )]
 #![warn(missing_debug_implementations)]
 #![allow(clippy::enum_glob_use)]
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+/// Contains the detection of a terminal's capabilities
+///
+/// Used by the [`SGRWriter`] to only write what a terminal supports
+#[cfg(not(feature = "macro-only"))]
+pub mod capability;
+/// Contains conversions between the different representations of a color
+///
+/// Used to find the closest color within a smaller palette, or to read & write hex colors
+#[cfg(not(feature = "macro-only"))]
+pub mod conversion;
 /// Implements SGR types that can be used standalone of a [`SGRString`]
 ///
 /// These types exist outside the context of a [`SGRString`], but
@@ -237,14 +298,64 @@ pub mod discrete;
 /// Contains the standard SGR implementations.
 ///
 /// Makes use of the [`writers`](writing) to write `SGR` codes to a writer
-#[cfg(not(feature = "macro-only"))]
+#[cfg(all(feature = "alloc", not(feature = "macro-only")))]
 pub mod graphics;
+/// Contains the parsing of styles written as words
+///
+/// Used to read styles from the same syntax as the macros or git's config
+#[cfg(all(feature = "alloc", not(feature = "macro-only")))]
+pub mod keywords;
+/// Contains the parsing of `LS_COLORS`, the styles `ls` gives to files
+///
+/// Used to style paths the same way `ls` does
+#[cfg(all(feature = "alloc", not(feature = "macro-only")))]
+pub mod ls_colors;
+/// Contains the parsing of `SGR` codes back into a [`SGRString`]
+///
+/// Used to read or strip text that has already been styled
+#[cfg(all(feature = "alloc", not(feature = "macro-only")))]
+pub mod parsing;
+/// Contains spans of styled text that can be nested within each other
+///
+/// Used to compose styles without resetting the styles surrounding them
+#[cfg(all(feature = "alloc", not(feature = "macro-only")))]
+pub mod span;
+/// Contains the state of a terminal's attributes
+///
+/// Used to write only the codes that change between two states
+#[cfg(all(feature = "alloc", not(feature = "macro-only")))]
+pub mod state;
+/// Contains styles that can be constructed at compile time
+///
+/// Used to declare styles & their escapes as constants
+#[cfg(not(feature = "macro-only"))]
+pub mod style;
+/// Contains the parsing of `:` separated `key=codes` lists into styles
+///
+/// Used to honour variables such as `GREP_COLORS` & `GCC_COLORS`
+#[cfg(all(feature = "alloc", not(feature = "macro-only")))]
+pub mod style_map;
+/// Contains themes, giving styles to semantic roles
+///
+/// Used to keep the styles of an application in one place, customisable at runtime
+#[cfg(all(feature = "alloc", not(feature = "macro-only")))]
+pub mod theme;
+/// Contains the measuring of text as it is displayed in a terminal
+///
+/// Used to align text containing `SGR` codes
+#[cfg(all(feature = "alloc", not(feature = "macro-only")))]
+pub mod width;
 /// Contains various structs and traits to help in writing `SGR` codes
 #[cfg(not(feature = "macro-only"))]
 pub mod writing;
 
 #[cfg(not(feature = "macro-only"))]
-pub use self::{discrete::*, graphics::*, writing::*};
+pub use self::{capability::*, conversion::*, discrete::*, style::*, writing::*};
+#[cfg(all(feature = "alloc", not(feature = "macro-only")))]
+pub use self::{
+    graphics::*, keywords::*, ls_colors::*, parsing::*, span::*, state::*, style_map::*, theme::*,
+    width::*,
+};
 
 #[cfg(feature = "macros")]
 pub use easy_sgr_macros::*;
@@ -0,0 +1,429 @@
+use core::{error::Error, fmt::Display, str::FromStr};
+#[cfg(feature = "std")]
+use std::{
+    env,
+    fs::{self, Metadata},
+    path::Path,
+};
+
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+
+use crate::{CleanKind, EasySGR, ParseCodesError, SGRString};
+
+/// The styles `ls` gives to files, as read from the `LS_COLORS` environment variable
+///
+/// `LS_COLORS` is a `:` separated list of `key=codes` entries, where `key` is either
+/// a [`FileKind`]'s two letter indicator, i.e. `di`, or a `*` followed by a suffix, i.e. `*.rs`.
+/// `codes` are the parameters of an SGR sequence as read by [`SGRString::from_codes`].
+///
+/// Like `ls`, entries override the defaults of [`LsColors::default`],
+/// later suffixes take precedence over earlier ones
+/// & every style is cleaned by [`CleanKind::Reset`]
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{ColorKind, FileKind, LsColors, Painted, StyleKind};
+///
+///let colors: LsColors = "di=01;34:*.rs=38;5;208".parse().unwrap();
+///
+///let dir = colors.get(FileKind::Directory).unwrap();
+///assert_eq!(dir.bold, StyleKind::Place);
+///assert_eq!(dir.foreground, ColorKind::Blue);
+///
+///let rust = colors.style_for_name("main.rs").unwrap();
+///assert_eq!(rust.foreground, ColorKind::Byte(208));
+///
+///let name = "main.rs";
+///assert_eq!(
+///    Painted::or_plain(colors.style_for_name(name), name).to_string(),
+///    "\x1b[38;5;208mmain.rs\x1b[0m"
+///);
+///assert_eq!(Painted::or_plain(colors.style_for_name("a.c"), "a.c").to_string(), "a.c");
+///```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LsColors {
+    /// The style of each kind of file
+    kinds: BTreeMap<FileKind, SGRString>,
+    /// The style of each suffix, in the order they were read
+    suffixes: Vec<(String, SGRString)>,
+    /// Whether links are styled as the file they point to, through `ln=target`
+    link_target: bool,
+}
+impl LsColors {
+    /// Creates a new [`LsColors`] holding no styles
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            kinds: BTreeMap::new(),
+            suffixes: Vec::new(),
+            link_target: false,
+        }
+    }
+    /// Reads `LS_COLORS` from the environment
+    ///
+    /// Returns [`LsColors::default`] if it is not set
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `LS_COLORS` is not valid, see [`LsColors::load`]
+    #[cfg(feature = "std")]
+    pub fn from_env() -> Result<Self, ParseLsColorsError> {
+        env::var("LS_COLORS").map_or_else(|_| Ok(Self::default()), |s| s.parse())
+    }
+    /// Loads the entries of the given `LS_COLORS` string, overriding the styles already set
+    ///
+    /// Empty entries are skipped, as are the `lc`, `rc`, `ec` & `cl` entries
+    /// that change the escape written by `ls` rather than a style.
+    /// Like `ls`, kinds set to `00`, `0` or nothing are left without a style,
+    /// so they fall back to the next kind, see [`LsColors::style_for_kind`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an entry is not a valid `key=codes` pair,
+    /// in which case no entries are loaded
+    pub fn load(&mut self, s: &str) -> Result<(), ParseLsColorsError> {
+        let mut loaded = self.clone();
+        for entry in s.split(':').filter(|entry| !entry.is_empty()) {
+            let (key, codes) = entry
+                .split_once('=')
+                .ok_or_else(|| ParseLsColorsError::Separator(String::from(entry)))?;
+            if key == "ln" && codes == "target" {
+                loaded.link_target = true;
+                continue;
+            }
+            if matches!(key, "lc" | "rc" | "ec" | "cl") {
+                continue;
+            }
+            let style = SGRString::from_codes(codes)
+                .map_err(|e| ParseLsColorsError::Codes(String::from(key), e))?
+                .clean(CleanKind::Reset);
+            if let Some(suffix) = key.strip_prefix('*') {
+                loaded.suffixes.retain(|(other, _)| other != suffix);
+                loaded.suffixes.push((String::from(suffix), style));
+            } else {
+                let kind = key
+                    .parse()
+                    .map_err(|_| ParseLsColorsError::Key(String::from(key)))?;
+                if kind == FileKind::Link {
+                    loaded.link_target = false;
+                }
+                if kind != FileKind::Reset && matches!(codes, "" | "0" | "00") {
+                    loaded.kinds.remove(&kind);
+                } else {
+                    loaded.kinds.insert(kind, style);
+                }
+            }
+        }
+        *self = loaded;
+        Ok(())
+    }
+    /// Returns the style of the given kind of file, if it has one
+    #[must_use]
+    pub fn get(&self, kind: FileKind) -> Option<&SGRString> {
+        self.kinds.get(&kind)
+    }
+    /// Returns the style of the given file name's suffix, if it has one
+    ///
+    /// Suffixes matching the name's case are preferred,
+    /// otherwise they are matched ignoring ascii case
+    #[must_use]
+    pub fn style_for_name(&self, name: &str) -> Option<&SGRString> {
+        let rev = || self.suffixes.iter().rev();
+        rev()
+            .find(|(suffix, _)| name.ends_with(suffix.as_str()))
+            .or_else(|| {
+                rev().find(|(suffix, _)| {
+                    name.len()
+                        .checked_sub(suffix.len())
+                        .and_then(|start| name.get(start..))
+                        .is_some_and(|end| end.eq_ignore_ascii_case(suffix))
+                })
+            })
+            .map(|(_, style)| style)
+    }
+    /// Returns the style of the given kind of file with the given name,
+    /// choosing between the styles the same way `ls` does
+    ///
+    /// The suffix of [`FileKind::File`]s is used over their kind's style,
+    /// when neither exist [`FileKind::Normal`] is used.
+    /// [`FileKind::Orphan`] falls back to [`FileKind::Link`],
+    /// the kinds of regular files without a style fall back to [`FileKind::File`]
+    /// & the kinds of directories to [`FileKind::Directory`],
+    /// sticky & other writable ones trying [`FileKind::OtherWritable`] then [`FileKind::Sticky`] first
+    #[must_use]
+    pub fn style_for_kind(&self, kind: FileKind, name: &str) -> Option<&SGRString> {
+        use FileKind::*;
+        let style = match kind {
+            File => self.style_for_name(name).or_else(|| self.get(kind)),
+            Orphan => self.get(kind).or_else(|| self.get(Link)),
+            MultiHardLink | Setuid | Setgid | Capability | Executable => {
+                self.get(kind).or_else(|| self.style_for_kind(File, name))
+            }
+            StickyOtherWritable => self
+                .get(kind)
+                .or_else(|| self.get(OtherWritable))
+                .or_else(|| self.get(Sticky))
+                .or_else(|| self.get(Directory)),
+            OtherWritable | Sticky => self.get(kind).or_else(|| self.get(Directory)),
+            kind => self.get(kind),
+        };
+        style.or_else(|| self.get(Normal))
+    }
+    /// Returns the style of the file at the given path
+    ///
+    /// Reads the file's metadata without following links,
+    /// files that cannot be read are styled as [`FileKind::Missing`]
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn style_for_path(&self, path: impl AsRef<Path>) -> Option<&SGRString> {
+        let path = path.as_ref();
+        fs::symlink_metadata(path).map_or_else(
+            |_| self.style_for_kind(FileKind::Missing, &file_name(path)),
+            |metadata| self.style_for_metadata(path, &metadata),
+        )
+    }
+    /// Returns the style of the file at the given path, with the given metadata
+    ///
+    /// The metadata should be read without following links,
+    /// links are only followed to check whether they are orphans
+    /// or when styled as their target, by its name & kind, through `ln=target`
+    #[must_use]
+    #[cfg(feature = "std")]
+    pub fn style_for_metadata(&self, path: &Path, metadata: &Metadata) -> Option<&SGRString> {
+        let kind = FileKind::of(path, metadata);
+        if kind == FileKind::Link && self.link_target {
+            if let (Ok(target), Ok(name)) = (fs::metadata(path), fs::read_link(path)) {
+                return self.style_for_kind(FileKind::of(path, &target), &file_name(&name));
+            }
+        }
+        self.style_for_kind(kind, &file_name(path))
+    }
+}
+impl Default for LsColors {
+    /// Creates the styles `ls` uses when `LS_COLORS` is not set
+    fn default() -> Self {
+        let mut colors = Self::new();
+        colors
+            .load(
+                "rs=0:di=01;34:ln=01;36:pi=33:so=01;35:do=01;35:bd=01;33:cd=01;33:\
+                ex=01;32:su=37;41:sg=30;43:tw=30;42:ow=34;42:st=37;44",
+            )
+            .expect("the default LS_COLORS should be valid");
+        colors
+    }
+}
+impl FromStr for LsColors {
+    type Err = ParseLsColorsError;
+
+    /// Loads the given `LS_COLORS` string over [`LsColors::default`],
+    /// see [`LsColors::load`]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut colors = Self::default();
+        colors.load(s)?;
+        Ok(colors)
+    }
+}
+/// Returns the file name of the given path, or the whole path if it has none
+#[cfg(feature = "std")]
+fn file_name(path: &Path) -> alloc::borrow::Cow<'_, str> {
+    path.file_name()
+        .unwrap_or(path.as_os_str())
+        .to_string_lossy()
+}
+/// Component of [`LsColors`]; a kind of file, as told apart by `ls`
+///
+/// Each is written in `LS_COLORS` as its two letter indicator
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FileKind {
+    /// Anything not styled otherwise, `no`
+    Normal,
+    /// A regular file, `fi`
+    File,
+    /// The reset written after styled files, `rs`
+    Reset,
+    /// A directory, `di`
+    Directory,
+    /// A symbolic link, `ln`
+    Link,
+    /// A regular file with multiple hard links, `mh`
+    MultiHardLink,
+    /// A named pipe, `pi`
+    Pipe,
+    /// A socket, `so`
+    Socket,
+    /// A door, `do`
+    Door,
+    /// A block device, `bd`
+    BlockDevice,
+    /// A character device, `cd`
+    CharDevice,
+    /// A symbolic link to a file that does not exist, `or`
+    Orphan,
+    /// A file that does not exist, `mi`
+    Missing,
+    /// A file that is setuid, `su`
+    Setuid,
+    /// A file that is setgid, `sg`
+    Setgid,
+    /// A file with capabilities, `ca`
+    Capability,
+    /// A directory that is sticky & other writable, `tw`
+    StickyOtherWritable,
+    /// A directory that is other writable, `ow`
+    OtherWritable,
+    /// A directory that is sticky, `st`
+    Sticky,
+    /// An executable file, `ex`
+    Executable,
+}
+impl FileKind {
+    /// Returns the two letter indicator of the kind, i.e. `di`
+    #[must_use]
+    pub const fn indicator(self) -> &'static str {
+        use FileKind::*;
+        match self {
+            Normal => "no",
+            File => "fi",
+            Reset => "rs",
+            Directory => "di",
+            Link => "ln",
+            MultiHardLink => "mh",
+            Pipe => "pi",
+            Socket => "so",
+            Door => "do",
+            BlockDevice => "bd",
+            CharDevice => "cd",
+            Orphan => "or",
+            Missing => "mi",
+            Setuid => "su",
+            Setgid => "sg",
+            Capability => "ca",
+            StickyOtherWritable => "tw",
+            OtherWritable => "ow",
+            Sticky => "st",
+            Executable => "ex",
+        }
+    }
+    /// Returns the kind of the file at the given path, with the given metadata
+    ///
+    /// Permissions & the kinds of special files are only told apart on unix.
+    /// Capabilities & doors are never detected
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn of(path: &Path, metadata: &Metadata) -> Self {
+        let file_type = metadata.file_type();
+        if file_type.is_symlink() {
+            return if fs::metadata(path).is_ok() {
+                Self::Link
+            } else {
+                Self::Orphan
+            };
+        }
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::{FileTypeExt, MetadataExt};
+            let mode = metadata.mode();
+            let (sticky, other_writable) = (mode & 0o1000 != 0, mode & 0o002 != 0);
+            if file_type.is_dir() {
+                return match (sticky, other_writable) {
+                    (true, true) => Self::StickyOtherWritable,
+                    (false, true) => Self::OtherWritable,
+                    (true, false) => Self::Sticky,
+                    (false, false) => Self::Directory,
+                };
+            }
+            if file_type.is_fifo() {
+                return Self::Pipe;
+            } else if file_type.is_socket() {
+                return Self::Socket;
+            } else if file_type.is_block_device() {
+                return Self::BlockDevice;
+            } else if file_type.is_char_device() {
+                return Self::CharDevice;
+            } else if mode & 0o4000 != 0 {
+                return Self::Setuid;
+            } else if mode & 0o2000 != 0 {
+                return Self::Setgid;
+            } else if mode & 0o111 != 0 {
+                return Self::Executable;
+            } else if metadata.nlink() > 1 {
+                return Self::MultiHardLink;
+            }
+        }
+        if file_type.is_dir() {
+            Self::Directory
+        } else {
+            Self::File
+        }
+    }
+}
+impl FromStr for FileKind {
+    type Err = ParseFileKindError;
+
+    /// Parses a two letter indicator, i.e. `di`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        use FileKind::*;
+        [
+            Normal,
+            File,
+            Reset,
+            Directory,
+            Link,
+            MultiHardLink,
+            Pipe,
+            Socket,
+            Door,
+            BlockDevice,
+            CharDevice,
+            Orphan,
+            Missing,
+            Setuid,
+            Setgid,
+            Capability,
+            StickyOtherWritable,
+            OtherWritable,
+            Sticky,
+            Executable,
+        ]
+        .into_iter()
+        .find(|kind| kind.indicator() == s)
+        .ok_or(ParseFileKindError)
+    }
+}
+/// An error encountered while trying to parse a string into a [`FileKind`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseFileKindError;
+impl Display for ParseFileKindError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str("Unrecognised file kind indicator")
+    }
+}
+impl Error for ParseFileKindError {}
+/// An error encountered while trying to parse `LS_COLORS`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseLsColorsError {
+    /// An entry without an `=`
+    Separator(String),
+    /// A key that is neither a known indicator nor a suffix
+    Key(String),
+    /// A key with invalid codes, see [`SGRString::from_codes`]
+    Codes(String, ParseCodesError),
+}
+impl Display for ParseLsColorsError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Separator(entry) => write!(f, "Missing '=' in entry: {entry}"),
+            Self::Key(key) => write!(f, "Unrecognised key: {key}"),
+            Self::Codes(key, e) => write!(f, "{e} for key: {key}"),
+        }
+    }
+}
+impl Error for ParseLsColorsError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Codes(_, e) => Some(e),
+            _ => None,
+        }
+    }
+}
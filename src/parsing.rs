@@ -0,0 +1,536 @@
+use core::{error::Error, fmt::Display, iter::Peekable};
+#[cfg(feature = "std")]
+use std::io;
+
+use alloc::{string::String, vec, vec::Vec};
+
+use crate::{
+    writing::CodeBuffer, ColorEncoding, ColorKind, FontKind, IdeogramKind, Param, SGRBuilder,
+    SGRString, StyleKind, UnderlineKind,
+};
+
+/// Parses SGR sequences out of a [`str`]
+///
+/// Iterates over the text found in between sequences,
+/// returning each section as an [`SGRString`] holding the
+/// attributes in effect for it.
+///
+/// Escapes that are not SGR sequences are left within the text.
+/// Codes that are not recognised are kept in [`SGRString::custom_places`]
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{ColorKind, SGRParser, StyleKind};
+///
+///let mut parser = SGRParser::new("\x1b[1mBold\x1b[31m and red");
+///
+///let bold = parser.next().unwrap();
+///assert_eq!(bold.text, "Bold");
+///assert_eq!(bold.bold, StyleKind::Place);
+///
+///let red = parser.next().unwrap();
+///assert_eq!(red.text, " and red");
+///assert_eq!(red.bold, StyleKind::Place);
+///assert_eq!(red.foreground, ColorKind::Red);
+///
+///assert_eq!(parser.next(), None);
+///```
+#[derive(Debug, Clone)]
+pub struct SGRParser<'a> {
+    /// The part of the string not yet parsed
+    rest: &'a str,
+    /// The attributes currently in effect
+    state: SGRString,
+}
+impl<'a> SGRParser<'a> {
+    /// Creates a new parser over the given [`str`]
+    #[must_use]
+    pub fn new(s: &'a str) -> Self {
+        Self {
+            rest: s,
+            state: SGRString::default(),
+        }
+    }
+    /// Returns the attributes currently in effect
+    ///
+    /// Does not contain any text
+    #[must_use]
+    pub const fn state(&self) -> &SGRString {
+        &self.state
+    }
+}
+impl<'a> From<&'a str> for SGRParser<'a> {
+    fn from(value: &'a str) -> Self {
+        Self::new(value)
+    }
+}
+impl Iterator for SGRParser<'_> {
+    type Item = SGRString;
+
+    /// Returns the next section of text
+    ///
+    /// Sequences found at the end of the string, with no text
+    /// following them, are returned as an [`SGRString`] with empty text
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut applied = false;
+        while let Some((0, end, params)) = find_sgr(self.rest) {
+            apply_params(&mut self.state, &params);
+            self.rest = &self.rest[end..];
+            applied = true;
+        }
+        let text_end = find_sgr(self.rest).map_or(self.rest.len(), |(start, _, _)| start);
+        if text_end == 0 && !applied {
+            return None;
+        }
+        let (text, rest) = self.rest.split_at(text_end);
+        self.rest = rest;
+        Some(SGRString {
+            text: String::from(text),
+            ..self.state.clone()
+        })
+    }
+}
+impl SGRString {
+    /// Parses the parameters of an SGR sequence, without its escape & end,
+    /// into an [`SGRString`] with no text
+    ///
+    /// Codes are applied the same way as [`SGRParser`] applies them,
+    /// an empty string being read as no codes at all rather than a reset
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any parameter is not a valid code
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{ColorKind, SGRString, StyleKind};
+    ///
+    ///let sgr = SGRString::from_codes("01;38;5;208").unwrap();
+    ///assert_eq!(sgr.bold, StyleKind::Place);
+    ///assert_eq!(sgr.foreground, ColorKind::Byte(208));
+    ///
+    ///assert!(SGRString::from_codes("1;red").is_err());
+    ///```
+    pub fn from_codes(s: &str) -> Result<Self, ParseCodesError> {
+        let mut sgr = Self::default();
+        if s.is_empty() {
+            return Ok(sgr);
+        }
+        let params = s
+            .split(';')
+            .map(|param| parse_param(param).ok_or_else(|| ParseCodesError(String::from(param))))
+            .collect::<Result<Vec<_>, _>>()?;
+        apply_params(&mut sgr, &params);
+        Ok(sgr)
+    }
+    /// Writes the parameters placing the [`SGRString`], without its escape & end,
+    /// the inverse of [`SGRString::from_codes`]
+    ///
+    /// Parameters are written using [`ColorEncoding::Semicolon`]
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{Color::*, EasySGR, SGRString, Style::*};
+    ///
+    ///let sgr = Bold.color(ByteFg(208));
+    ///assert_eq!(sgr.to_codes(), "38;5;208;1");
+    ///assert_eq!(SGRString::from_codes(&sgr.to_codes()), Ok(sgr));
+    ///```
+    #[must_use]
+    pub fn to_codes(&self) -> String {
+        let mut builder = SGRBuilder::default();
+        self.place_all(&mut builder);
+        let mut codes = String::new();
+        for param in builder.0 {
+            let mut buf: CodeBuffer = CodeBuffer::new();
+            param.encode_into(&mut buf, ColorEncoding::Semicolon);
+            if !codes.is_empty() {
+                codes.push(';');
+            }
+            codes.push_str(buf.as_str());
+        }
+        codes
+    }
+}
+/// An error encountered while trying to parse SGR codes, see [`SGRString::from_codes`]
+///
+/// Holds the parameter that is not a valid code
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseCodesError(pub String);
+impl Display for ParseCodesError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "Invalid SGR parameter: {}", self.0)
+    }
+}
+impl Error for ParseCodesError {}
+/// Removes all SGR sequences from the given [`str`]
+///
+/// All other text, including escapes that are not SGR sequences, is left intact
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::strip_sgr;
+///
+///assert_eq!(strip_sgr("\x1b[31;1mThis was red & bold\x1b[0m"), "This was red & bold");
+///```
+#[must_use]
+pub fn strip_sgr(s: &str) -> String {
+    let mut buf = String::with_capacity(s.len());
+    let mut rest = s;
+    while let Some((start, end)) = find_sequence(rest) {
+        buf.push_str(&rest[..start]);
+        rest = &rest[end..];
+    }
+    buf.push_str(rest);
+    buf
+}
+/// An [`io::Write`] adapter that removes SGR sequences from the bytes written through it
+///
+/// Sequences split across multiple calls to [`io::Write::write`] are still removed,
+/// the start of a possible sequence being held back until it is either completed or broken.
+/// Use [`StripWriter::finish`] to write out anything held back
+///
+/// # Examples
+///
+///```rust
+///use std::io::Write;
+///use easy_sgr::StripWriter;
+///
+///let mut writer = StripWriter::new(Vec::new());
+///writer.write_all(b"\x1b[3").unwrap();
+///writer.write_all(b"1mThis was red\x1b[0m").unwrap();
+///
+///assert_eq!(writer.finish().unwrap(), b"This was red");
+///```
+#[derive(Debug, Clone)]
+#[cfg(feature = "std")]
+pub struct StripWriter<W: io::Write> {
+    /// The writer stripped bytes are written to
+    writer: W,
+    /// The start of a sequence that is yet to be completed
+    pending: Vec<u8>,
+}
+#[cfg(feature = "std")]
+impl<W: io::Write> StripWriter<W> {
+    /// Creates a new [`StripWriter`] writing to the given writer
+    #[must_use]
+    pub const fn new(writer: W) -> Self {
+        Self {
+            writer,
+            pending: Vec::new(),
+        }
+    }
+    /// Returns a reference to the inner writer
+    #[must_use]
+    pub const fn get_ref(&self) -> &W {
+        &self.writer
+    }
+    /// Writes any bytes held back then returns the inner writer
+    ///
+    /// Held back bytes are an unfinished sequence, so are written as normal text
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails
+    pub fn finish(mut self) -> io::Result<W> {
+        self.writer.write_all(&self.pending)?;
+        Ok(self.writer)
+    }
+}
+#[cfg(feature = "std")]
+impl<W: io::Write> io::Write for StripWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let mut text = Vec::with_capacity(self.pending.len() + buf.len());
+        for &byte in buf {
+            match (self.pending.as_slice(), byte) {
+                ([], 0x1b) | ([0x1b], b'[') => self.pending.push(byte),
+                ([0x1b, b'[', ..], byte) if is_param(byte) => self.pending.push(byte),
+                ([0x1b, b'[', ..], b'm') => self.pending.clear(),
+                ([], byte) => text.push(byte),
+                (_, byte) => {
+                    text.append(&mut self.pending);
+                    if byte == 0x1b {
+                        self.pending.push(byte);
+                    } else {
+                        text.push(byte);
+                    }
+                }
+            }
+        }
+        self.writer.write_all(&text)?;
+        Ok(buf.len())
+    }
+    /// Flushes the inner writer
+    ///
+    /// Bytes held back are not written, see [`StripWriter::finish`]
+    fn flush(&mut self) -> io::Result<()> {
+        self.writer.flush()
+    }
+}
+/// Finds the first SGR sequence within the given [`str`] that contains valid parameters
+///
+/// Returns the start & end of the sequence along with its parameters,
+/// or `None` if no valid sequence was found
+pub(crate) fn find_sgr(s: &str) -> Option<(usize, usize, Vec<Param>)> {
+    let mut offset = 0;
+    while let Some((start, end)) = find_sequence(&s[offset..]) {
+        let (start, end) = (start + offset, end + offset);
+        if let Some(params) = parse_params(&s[start + 2..end - 1]) {
+            return Some((start, end, params));
+        }
+        offset = start + 2;
+    }
+    None
+}
+/// Finds the first sequence shaped like an SGR sequence within the given [`str`]
+///
+/// Unlike [`find_sgr`] the parameters are not checked to be valid codes
+///
+/// Returns the start & end of the sequence
+pub(crate) fn find_sequence(s: &str) -> Option<(usize, usize)> {
+    let mut offset = 0;
+    while let Some(start) = s[offset..].find("\x1b[").map(|i| i + offset) {
+        let params_start = start + 2;
+        let params_end = s.as_bytes()[params_start..]
+            .iter()
+            .position(|&byte| !is_param(byte))
+            .map_or(s.len(), |len| params_start + len);
+        if s[params_end..].starts_with('m') {
+            return Some((start, params_end + 1));
+        }
+        offset = params_start;
+    }
+    None
+}
+/// Returns the end of the given [`str`] if it is the start of an unfinished SGR sequence,
+/// otherwise an empty [`str`]
+pub(crate) fn unfinished_sgr(s: &str) -> &str {
+    let Some(start) = s.rfind('\x1b') else {
+        return "";
+    };
+    match s.as_bytes()[start..] {
+        [0x1b] => &s[start..],
+        [0x1b, b'[', ref params @ ..] if params.iter().all(|&byte| is_param(byte)) => &s[start..],
+        _ => "",
+    }
+}
+/// Whether the given byte can be found within an SGR sequence's parameters
+const fn is_param(byte: u8) -> bool {
+    matches!(byte, b'0'..=b'9' | b';' | b':')
+}
+/// Parses the parameters of an SGR sequence
+///
+/// Empty parameters are read as `0`, returns `None` if any parameter
+/// is not a valid code, or has sub-parameters that are not understood
+fn parse_params(s: &str) -> Option<Vec<Param>> {
+    s.split(';').map(parse_param).collect()
+}
+/// Parses a single parameter, along with its colon separated sub-parameters
+///
+/// Extended colors may be written with or without the color space, i.e.
+/// both `38:2::<r>:<g>:<b>` & `38:2:<r>:<g>:<b>`
+fn parse_param(s: &str) -> Option<Param> {
+    let code = |code: &str| match code {
+        "" => Some(0),
+        code => code.parse().ok(),
+    };
+    let parts = s.split(':').map(code).collect::<Option<Vec<u8>>>()?;
+    match parts[..] {
+        [code] => Some(Param::Code(code)),
+        [code @ (38 | 48 | 58), 5, n] => Some(Param::Byte(code, n)),
+        [code @ (38 | 48 | 58), 2, _, r, g, b] | [code @ (38 | 48 | 58), 2, r, g, b] => {
+            Some(Param::Rgb(code, r, g, b))
+        }
+        [code, sub] => Some(Param::Sub(code, sub)),
+        _ => None,
+    }
+}
+/// Applies the given parameters to an [`SGRString`]
+///
+/// Parameters are applied the same way a terminal would, meaning
+/// `0` resets the state & later codes override earlier ones.
+///
+/// Sub-parameters other than an underline's style are ignored
+pub(crate) fn apply_params(sgr: &mut SGRString, params: &[Param]) {
+    use StyleKind::*;
+    let mut params = params.iter().copied().peekable();
+    while let Some(param) = params.next() {
+        let code = match param {
+            Param::Sub(4, sub) => {
+                if let Some(underline) = underline(sub) {
+                    (sgr.underline, sgr.underline_style) = underline;
+                    sgr.doubly_underlined = StyleKind::None;
+                }
+                continue;
+            }
+            Param::Byte(code, n) => {
+                set_color(sgr, code, ColorKind::Byte(n));
+                continue;
+            }
+            Param::Rgb(code, r, g, b) => {
+                set_color(sgr, code, ColorKind::Rgb(r, g, b));
+                continue;
+            }
+            Param::Code(code) | Param::Sub(code, _) => code,
+        };
+        match code {
+            0 => {
+                *sgr = SGRString {
+                    reset: true,
+                    ..Default::default()
+                };
+            }
+            1 => sgr.bold = Place,
+            2 => sgr.dim = Place,
+            3 => sgr.italic = Place,
+            4 => {
+                (sgr.underline, sgr.underline_style) = (Place, UnderlineKind::None);
+                sgr.doubly_underlined = StyleKind::None;
+            }
+            5 => sgr.blinking = Place,
+            7 => sgr.inverse = Place,
+            8 => sgr.hidden = Place,
+            9 => sgr.strikethrough = Place,
+            22 => (sgr.bold, sgr.dim) = (Clean, Clean),
+            23 => sgr.italic = Clean,
+            24 => {
+                (sgr.underline, sgr.underline_style) = (Clean, UnderlineKind::None);
+                sgr.doubly_underlined = StyleKind::None;
+            }
+            25 => sgr.blinking = Clean,
+            27 => sgr.inverse = Clean,
+            28 => sgr.hidden = Clean,
+            29 => sgr.strikethrough = Clean,
+            10 => sgr.font = FontKind::Primary,
+            11..=19 => sgr.font = FontKind::Alternate(code - 10),
+            20 => sgr.font = FontKind::Fraktur,
+            21 => {
+                (sgr.underline, sgr.underline_style) = (StyleKind::None, UnderlineKind::None);
+                sgr.doubly_underlined = Place;
+            }
+            26 => sgr.proportional_spacing = Place,
+            50 => sgr.proportional_spacing = Clean,
+            51 => sgr.framed = Place,
+            52 => sgr.encircled = Place,
+            54 => (sgr.framed, sgr.encircled) = (Clean, Clean),
+            53 => sgr.overline = Place,
+            55 => sgr.overline = Clean,
+            60..=65 => sgr.ideogram = ideogram(code),
+            73 => (sgr.superscript, sgr.subscript) = (Place, StyleKind::None),
+            74 => (sgr.superscript, sgr.subscript) = (StyleKind::None, Place),
+            75 => (sgr.superscript, sgr.subscript) = (Clean, Clean),
+            30..=37 => sgr.foreground = standard_color(code - 30),
+            40..=47 => sgr.background = standard_color(code - 40),
+            90..=97 => sgr.foreground = bright_color(code - 90),
+            100..=107 => sgr.background = bright_color(code - 100),
+            39 => sgr.foreground = ColorKind::Default,
+            49 => sgr.background = ColorKind::Default,
+            59 => sgr.underline_color = ColorKind::Default,
+            38 | 48 | 58 => match extended_color(&mut params) {
+                Ok(color) => set_color(sgr, code, color),
+                Err(rest) => {
+                    sgr.custom_places.push(code);
+                    sgr.custom_places.extend_from_slice(&rest);
+                }
+            },
+            code => sgr.custom_places.push(code),
+        }
+    }
+}
+/// Sets the color of an extended color code, `38`, `48` or `58`
+const fn set_color(sgr: &mut SGRString, code: u8, color: ColorKind) {
+    match code {
+        38 => sgr.foreground = color,
+        48 => sgr.background = color,
+        _ => sgr.underline_color = color,
+    }
+}
+/// Returns the [`IdeogramKind`] of an ideogram code, `60`-`65`
+const fn ideogram(code: u8) -> IdeogramKind {
+    match code {
+        60 => IdeogramKind::Underline,
+        61 => IdeogramKind::DoubleUnderline,
+        62 => IdeogramKind::Overline,
+        63 => IdeogramKind::DoubleOverline,
+        64 => IdeogramKind::Stress,
+        _ => IdeogramKind::Clean,
+    }
+}
+/// Returns the state of the underline for the sub-parameter of a `4`
+///
+/// i.e. `3` for `4:3`, returning `None` for unknown styles
+const fn underline(sub: u8) -> Option<(StyleKind, UnderlineKind)> {
+    match sub {
+        0 => Some((StyleKind::Clean, UnderlineKind::None)),
+        1 => Some((StyleKind::None, UnderlineKind::Single)),
+        2 => Some((StyleKind::None, UnderlineKind::Double)),
+        3 => Some((StyleKind::None, UnderlineKind::Curly)),
+        4 => Some((StyleKind::None, UnderlineKind::Dotted)),
+        5 => Some((StyleKind::None, UnderlineKind::Dashed)),
+        _ => None,
+    }
+}
+/// Returns the [`ColorKind`] of a standard color's offset
+///
+/// i.e. `1` for `31` or `41`
+const fn standard_color(offset: u8) -> ColorKind {
+    use ColorKind::*;
+    match offset {
+        0 => Black,
+        1 => Red,
+        2 => Green,
+        3 => Yellow,
+        4 => Blue,
+        5 => Magenta,
+        6 => Cyan,
+        _ => White,
+    }
+}
+/// Returns the [`ColorKind`] of a bright color's offset
+///
+/// i.e. `1` for `91` or `101`
+const fn bright_color(offset: u8) -> ColorKind {
+    use ColorKind::*;
+    match offset {
+        0 => BrightBlack,
+        1 => BrightRed,
+        2 => BrightGreen,
+        3 => BrightYellow,
+        4 => BrightBlue,
+        5 => BrightMagenta,
+        6 => BrightCyan,
+        _ => BrightWhite,
+    }
+}
+/// Reads the codes following a `38`, `48` or `58`
+///
+/// Stops before the first parameter that is not a plain code,
+/// leaving it to be applied on its own
+///
+/// # Errors
+///
+/// Returns the codes consumed when they do not form a valid color
+fn extended_color(
+    params: &mut Peekable<impl Iterator<Item = Param>>,
+) -> Result<ColorKind, Vec<u8>> {
+    let mut codes = core::iter::from_fn(|| match params.peek() {
+        Some(&Param::Code(code)) => {
+            params.next();
+            Some(code)
+        }
+        _ => None,
+    });
+    match codes.next() {
+        Some(5) => codes.next().map(ColorKind::Byte).ok_or_else(|| vec![5]),
+        Some(2) => match (codes.next(), codes.next(), codes.next()) {
+            (Some(r), Some(g), Some(b)) => Ok(ColorKind::Rgb(r, g, b)),
+            (r, g, _) => Err([Some(2), r, g].into_iter().flatten().collect()),
+        },
+        Some(n) => Err(vec![n]),
+        None => Err(Vec::new()),
+    }
+}
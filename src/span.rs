@@ -0,0 +1,142 @@
+use core::fmt::Display;
+
+use alloc::{string::String, vec, vec::Vec};
+
+use crate::{
+    parsing::apply_params, CapableBuilder, CapableWriter, SGRBuilder, SGRString, SGRWriter,
+};
+
+/// A styled span of text, holding plain text and nested spans
+///
+/// When written, each span places its style, writes its children,
+/// then writes only the codes needed to restore the style of its parent,
+/// instead of resetting everything.
+///
+/// The text, clean & pad of the [`SGRString`] used as style are ignored,
+/// its text is turned into a child when converted with [`SGRSpan::new`]
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{Color::*, EasySGR, SGRSpan, Style::*};
+///
+///let span = SGRSpan::new(RedFg.text("A red sentence with a "))
+///    .span(Bold.color(BlueFg).text("bold blue"))
+///    .text(" word");
+///
+///assert_eq!(
+///    span.to_string(),
+///    "\x1b[31mA red sentence with a \x1b[34;1mbold blue\x1b[31;22m word\x1b[39m"
+///);
+///```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SGRSpan {
+    /// The style placed for the span & its children
+    pub style: SGRString,
+    /// The text & spans within the span, written in order
+    pub children: Vec<SpanChild>,
+}
+impl SGRSpan {
+    /// Creates a new [`SGRSpan`] styled by the given [`SGRString`]
+    ///
+    /// If the [`SGRString`] has text, it is kept as the first child
+    #[must_use]
+    pub fn new(style: impl Into<SGRString>) -> Self {
+        let mut style = style.into();
+        let text = core::mem::take(&mut style.text);
+        let children = if text.is_empty() {
+            Vec::new()
+        } else {
+            vec![SpanChild::Text(text)]
+        };
+        Self { style, children }
+    }
+    /// Adds plain text as the last child
+    #[must_use]
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.children.push(SpanChild::Text(text.into()));
+        self
+    }
+    /// Adds a nested span as the last child
+    #[must_use]
+    pub fn span(mut self, span: impl Into<Self>) -> Self {
+        self.children.push(SpanChild::Span(span.into()));
+        self
+    }
+    /// Writes the span & all its children to the given [`SGRWriter`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    /// Error type specified by [`CapableWriter::Error`]
+    pub fn write_to<W: CapableWriter>(&self, writer: &mut SGRWriter<W>) -> Result<(), W::Error> {
+        self.write_within(writer, &mut vec![SGRString::default()])
+    }
+    /// Writes the span, using the top of the stack as the parent's style
+    fn write_within<W: CapableWriter>(
+        &self,
+        writer: &mut SGRWriter<W>,
+        stack: &mut Vec<SGRString>,
+    ) -> Result<(), W::Error> {
+        let mut builder = SGRBuilder::default();
+        self.style.place_all(&mut builder);
+        let outer = stack.last().cloned().unwrap_or_default();
+        let mut state = outer.clone();
+        apply_params(&mut state, &builder.0);
+        writer.write_builder(&mut builder)?;
+
+        stack.push(state);
+        for child in &self.children {
+            match child {
+                SpanChild::Text(text) => writer.write_inner(text)?,
+                SpanChild::Span(span) => span.write_within(writer, stack)?,
+            }
+        }
+        stack.pop();
+
+        let mut builder = SGRBuilder::default();
+        if self.style.reset {
+            builder.write_code(0);
+            outer.place_colors(&mut builder);
+            outer.place_styles(&mut builder);
+        } else {
+            self.style.clean_colors_within(&outer, &mut builder);
+            self.style.clean_styles_within(&outer, &mut builder);
+        }
+        self.style.clean_custom(&mut builder);
+        writer.write_builder(&mut builder)
+    }
+}
+impl<I: Into<SGRString>> From<I> for SGRSpan {
+    fn from(value: I) -> Self {
+        Self::new(value)
+    }
+}
+impl Display for SGRSpan {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.write_to(&mut SGRWriter::from(f))
+    }
+}
+/// Component of [`SGRSpan`]; a single child
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SpanChild {
+    /// Plain text, styled by the enclosing spans
+    Text(String),
+    /// A nested span
+    Span(SGRSpan),
+}
+impl From<&str> for SpanChild {
+    fn from(value: &str) -> Self {
+        Self::Text(String::from(value))
+    }
+}
+impl From<String> for SpanChild {
+    fn from(value: String) -> Self {
+        Self::Text(value)
+    }
+}
+impl From<SGRSpan> for SpanChild {
+    fn from(value: SGRSpan) -> Self {
+        Self::Span(value)
+    }
+}
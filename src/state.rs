@@ -0,0 +1,278 @@
+use alloc::vec::Vec;
+
+use crate::{
+    parsing::apply_params, style::STYLE_CODES, CapableBuilder, ColorKind, FontKind, IdeogramKind,
+    Param, SGRBuilder, SGRString, StyleKind, UnderlineKind,
+};
+
+/// The attributes in effect within a terminal
+///
+/// Unlike an [`SGRString`], which holds what is written,
+/// each attribute is either in effect or not.
+///
+/// Used to find the codes needed to go from one state to another
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{Color::*, EasySGR, Param::Code, SGRState, Style::*};
+///
+///let prev = SGRState::from(&Bold.style(Dim).color(RedFg));
+///let next = SGRState::from(&Dim.color(RedFg));
+///
+///assert_eq!(prev.diff(&next).0, [Code(22), Code(2)]);
+///```
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SGRState {
+    /// The foreground color, [`ColorKind::None`] being the default
+    pub foreground: ColorKind,
+    /// The background color, [`ColorKind::None`] being the default
+    pub background: ColorKind,
+    /// The underline color, [`ColorKind::None`] being the default
+    pub underline_color: ColorKind,
+    /// Whether text is bold
+    pub bold: bool,
+    /// Whether text is dim
+    pub dim: bool,
+    /// Whether text is italic
+    pub italic: bool,
+    /// The underline, [`UnderlineKind::None`] being no underline
+    pub underline: UnderlineKind,
+    /// Whether text is blinking
+    pub blinking: bool,
+    /// Whether text is inverted
+    pub inverse: bool,
+    /// Whether text is hidden
+    pub hidden: bool,
+    /// Whether text is struckthrough
+    pub strikethrough: bool,
+    /// Whether text is doubly underlined, through `21`
+    pub doubly_underlined: bool,
+    /// Whether text uses proportional spacing
+    pub proportional_spacing: bool,
+    /// Whether text is framed
+    pub framed: bool,
+    /// Whether text is encircled
+    pub encircled: bool,
+    /// Whether text is overlined
+    pub overline: bool,
+    /// Whether text is superscript
+    pub superscript: bool,
+    /// Whether text is subscript
+    pub subscript: bool,
+    /// The font, [`FontKind::None`] being the primary font
+    pub font: FontKind,
+    /// The ideogram style, [`IdeogramKind::None`] being no ideogram
+    pub ideogram: IdeogramKind,
+}
+impl SGRState {
+    /// Applies the given parameters, the same way a terminal would
+    pub fn apply(&mut self, params: &[Param]) {
+        self.apply_custom(params);
+    }
+    /// Applies the given parameters, returning the codes that are not understood
+    pub(crate) fn apply_custom(&mut self, params: &[Param]) -> Vec<u8> {
+        let mut sgr = SGRString::from(&*self);
+        apply_params(&mut sgr, params);
+        *self = Self::from(&sgr);
+        sgr.custom_places
+    }
+    /// Returns the shortest codes that transition from this state to `next`
+    ///
+    /// Either the codes that change each attribute that differs,
+    /// or a reset followed by every attribute of `next`.
+    ///
+    /// Codes such as `22` undo more than one attribute,
+    /// so the attributes of `next` they undo are placed again.
+    #[must_use]
+    pub fn diff(&self, next: &Self) -> SGRBuilder {
+        let prev = self.params();
+        let next = next.params();
+
+        let mut resets = Vec::new();
+        for ((prev, not), (next, _)) in prev.iter().zip(&next) {
+            if prev.is_some() && next.is_none() && !resets.contains(not) {
+                resets.push(*not);
+            }
+        }
+        let mut delta = SGRBuilder::default();
+        delta.write_codes(&resets);
+        for ((prev, not), (next, _)) in prev.iter().zip(&next) {
+            if let Some(next) = next {
+                if resets.contains(not) || *prev != Some(*next) {
+                    delta.write_param(*next);
+                }
+            }
+        }
+
+        let mut reset = SGRBuilder::default();
+        reset.write_code(0);
+        next.iter()
+            .filter_map(|(next, _)| *next)
+            .for_each(|next| reset.write_param(next));
+
+        if len(&reset.0) < len(&delta.0) {
+            reset
+        } else {
+            delta
+        }
+    }
+    /// Lists every attribute as the [`Param`] placing it, if in effect,
+    /// and the code that undoes it
+    fn params(&self) -> Vec<(Option<Param>, u8)> {
+        let color = |sgr: SGRString| {
+            let mut builder = SGRBuilder::default();
+            sgr.place_colors(&mut builder);
+            builder.0.first().copied()
+        };
+        let underline = match self.underline {
+            UnderlineKind::None => None,
+            UnderlineKind::Single => Some(Param::Code(4)),
+            ref kind => kind.sub().map(|sub| Param::Sub(4, sub)),
+        };
+        let colors = [
+            (
+                color(SGRString {
+                    foreground: self.foreground.clone(),
+                    ..Default::default()
+                }),
+                39,
+            ),
+            (
+                color(SGRString {
+                    background: self.background.clone(),
+                    ..Default::default()
+                }),
+                49,
+            ),
+            (
+                color(SGRString {
+                    underline_color: self.underline_color.clone(),
+                    ..Default::default()
+                }),
+                59,
+            ),
+        ];
+        let styles =
+            self.styles()
+                .into_iter()
+                .zip(STYLE_CODES)
+                .map(|(on, (place, not))| match place {
+                    // the underline's style is written along with it
+                    4 => (underline, not),
+                    _ => (on.then_some(Param::Code(place)), not),
+                });
+        colors
+            .into_iter()
+            .chain(styles)
+            .chain([
+                (self.font.code().map(Param::Code), 10),
+                (self.ideogram.code().map(Param::Code), 65),
+            ])
+            .collect()
+    }
+    /// Lists whether each attribute held as a [`StyleKind`] by an [`SGRString`]
+    /// is in effect, in the order of [`STYLE_CODES`]
+    const fn styles(&self) -> [bool; STYLE_CODES.len()] {
+        [
+            self.bold,
+            self.dim,
+            self.italic,
+            !matches!(self.underline, UnderlineKind::None),
+            self.blinking,
+            self.inverse,
+            self.hidden,
+            self.strikethrough,
+            self.doubly_underlined,
+            self.proportional_spacing,
+            self.framed,
+            self.encircled,
+            self.overline,
+            self.superscript,
+            self.subscript,
+        ]
+    }
+}
+impl From<&SGRString> for SGRState {
+    fn from(value: &SGRString) -> Self {
+        let color = |kind: &ColorKind| match kind {
+            ColorKind::Default => ColorKind::None,
+            kind => kind.clone(),
+        };
+        let on = |kind: &StyleKind| *kind == StyleKind::Place;
+        Self {
+            foreground: color(&value.foreground),
+            background: color(&value.background),
+            underline_color: color(&value.underline_color),
+            bold: on(&value.bold),
+            dim: on(&value.dim),
+            italic: on(&value.italic),
+            underline: match (&value.underline, &value.underline_style) {
+                (StyleKind::Place, UnderlineKind::None) => UnderlineKind::Single,
+                (_, kind) => kind.clone(),
+            },
+            blinking: on(&value.blinking),
+            inverse: on(&value.inverse),
+            hidden: on(&value.hidden),
+            strikethrough: on(&value.strikethrough),
+            doubly_underlined: on(&value.doubly_underlined),
+            proportional_spacing: on(&value.proportional_spacing),
+            framed: on(&value.framed),
+            encircled: on(&value.encircled),
+            overline: on(&value.overline),
+            superscript: on(&value.superscript),
+            subscript: on(&value.subscript),
+            font: match value.font {
+                FontKind::Primary => FontKind::None,
+                ref font => font.clone(),
+            },
+            ideogram: match value.ideogram {
+                IdeogramKind::Clean => IdeogramKind::None,
+                ref ideogram => ideogram.clone(),
+            },
+        }
+    }
+}
+impl From<&SGRState> for SGRString {
+    fn from(value: &SGRState) -> Self {
+        let style = |on| {
+            if on {
+                StyleKind::Place
+            } else {
+                StyleKind::None
+            }
+        };
+        Self {
+            foreground: value.foreground.clone(),
+            background: value.background.clone(),
+            underline_color: value.underline_color.clone(),
+            bold: style(value.bold),
+            dim: style(value.dim),
+            italic: style(value.italic),
+            underline: style(value.underline == UnderlineKind::Single),
+            underline_style: match value.underline {
+                UnderlineKind::Single => UnderlineKind::None,
+                ref kind => kind.clone(),
+            },
+            blinking: style(value.blinking),
+            inverse: style(value.inverse),
+            hidden: style(value.hidden),
+            strikethrough: style(value.strikethrough),
+            doubly_underlined: style(value.doubly_underlined),
+            proportional_spacing: style(value.proportional_spacing),
+            framed: style(value.framed),
+            encircled: style(value.encircled),
+            overline: style(value.overline),
+            superscript: style(value.superscript),
+            subscript: style(value.subscript),
+            font: value.font.clone(),
+            ideogram: value.ideogram.clone(),
+            ..Default::default()
+        }
+    }
+}
+/// The length of the given parameters once written, separators included
+fn len(params: &[Param]) -> usize {
+    params.iter().map(|param| param.len() + 1).sum()
+}
@@ -0,0 +1,789 @@
+use core::fmt::Display;
+
+#[cfg(feature = "alloc")]
+use crate::SGRString;
+use crate::{conversion::Layer, writing::CodeBuffer, Color, ColorEncoding, Param, Style};
+
+/// The code placing & the code undoing each attribute held as a [`StyleKind`],
+/// in the order they are written
+///
+/// Those holding these attributes list them in this same order
+pub(crate) const STYLE_CODES: [(u8, u8); 15] = [
+    (1, 22),  // bold
+    (2, 22),  // dim
+    (3, 23),  // italic
+    (4, 24),  // underline
+    (5, 25),  // blinking
+    (7, 27),  // inverse
+    (8, 28),  // hidden
+    (9, 29),  // strikethrough
+    (21, 24), // doubly underlined
+    (26, 50), // proportional spacing
+    (51, 54), // framed
+    (52, 54), // encircled
+    (53, 55), // overline
+    (73, 75), // superscript
+    (74, 75), // subscript
+];
+/// Returns the index within [`STYLE_CODES`] of the attribute placed by the given code
+///
+/// # Panics
+///
+/// Panics if no attribute is placed by the code
+const fn style_index(place: u8) -> usize {
+    let mut i = 0;
+    while STYLE_CODES[i].0 != place {
+        i += 1;
+    }
+    i
+}
+/// What applying a [`Style`] changes, see [`Style::change`]
+pub(crate) enum StyleChange {
+    /// Resets all attributes before the others are placed
+    Reset,
+    /// Sets the attribute at the given index of [`STYLE_CODES`]
+    Style(usize, StyleKind),
+    /// Sets the style of the underline
+    Underline(UnderlineKind),
+    /// Sets the font
+    Font(FontKind),
+    /// Sets the ideogram style
+    Ideogram(IdeogramKind),
+}
+impl Style {
+    /// Returns what applying the style changes
+    pub(crate) const fn change(self) -> StyleChange {
+        use Style::*;
+        use StyleKind::*;
+        const fn style(place: u8, kind: StyleKind) -> StyleChange {
+            StyleChange::Style(style_index(place), kind)
+        }
+
+        match self {
+            Reset => StyleChange::Reset,
+            Bold => style(1, Place),
+            Dim => style(2, Place),
+            Italic => style(3, Place),
+            Underline => style(4, Place),
+            Blinking => style(5, Place),
+            Inverse => style(7, Place),
+            Hidden => style(8, Place),
+            Strikethrough => style(9, Place),
+            DoubleUnderline => StyleChange::Underline(UnderlineKind::Double),
+            CurlyUnderline => StyleChange::Underline(UnderlineKind::Curly),
+            DottedUnderline => StyleChange::Underline(UnderlineKind::Dotted),
+            DashedUnderline => StyleChange::Underline(UnderlineKind::Dashed),
+            DoublyUnderlined => style(21, Place),
+            ProportionalSpacing => style(26, Place),
+            Framed => style(51, Place),
+            Encircled => style(52, Place),
+            Overline => style(53, Place),
+            PrimaryFont => StyleChange::Font(FontKind::Primary),
+            AlternateFont(n) => StyleChange::Font(FontKind::Alternate(n)),
+            Fraktur => StyleChange::Font(FontKind::Fraktur),
+            IdeogramUnderline => StyleChange::Ideogram(IdeogramKind::Underline),
+            IdeogramDoubleUnderline => StyleChange::Ideogram(IdeogramKind::DoubleUnderline),
+            IdeogramOverline => StyleChange::Ideogram(IdeogramKind::Overline),
+            IdeogramDoubleOverline => StyleChange::Ideogram(IdeogramKind::DoubleOverline),
+            IdeogramStress => StyleChange::Ideogram(IdeogramKind::Stress),
+            Superscript => style(73, Place),
+            Subscript => style(74, Place),
+
+            NotBold => style(1, Clean),
+            NotDim => style(2, Clean),
+            NotItalic => style(3, Clean),
+            NotUnderline => style(4, Clean),
+            NotBlinking => style(5, Clean),
+            NotInverse => style(7, Clean),
+            NotHidden => style(8, Clean),
+            NotStrikethrough => style(9, Clean),
+            NotProportionalSpacing => style(26, Clean),
+            NotFramed => style(51, Clean),
+            NotEncircled => style(52, Clean),
+            NotOverline => style(53, Clean),
+            NotIdeogram => StyleChange::Ideogram(IdeogramKind::Clean),
+            NotSuperscript => style(73, Clean),
+            NotSubscript => style(74, Clean),
+        }
+    }
+}
+
+/// A style that can be constructed at compile time
+///
+/// Holds everything an [`SGRString`] does besides its text,
+/// with up to [`SGRStyle::CUSTOM_CAPACITY`] custom codes for each of
+/// [`SGRStyle::custom_places`] & [`SGRStyle::custom_cleans`].
+///
+/// Its escapes can be computed at compile time through
+/// [`SGRStyle::place_escape`] & [`SGRStyle::clean_escape`],
+/// or it can be turned into an [`SGRString`] through [`EasySGR`](crate::EasySGR) to style text
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{CleanKind, Color::*, EasySGR, SGRStyle, Style::*};
+///
+///const ERROR: SGRStyle = SGRStyle::new()
+///    .style(Bold)
+///    .color(RedFg)
+///    .clean(CleanKind::Reverse);
+///const ERROR_PLACE: &str = ERROR.place_escape().as_str();
+///const ERROR_CLEAN: &str = ERROR.clean_escape().as_str();
+///
+///assert_eq!(ERROR_PLACE, "\x1b[31;1m");
+///assert_eq!(ERROR_CLEAN, "\x1b[39;22m");
+///assert_eq!(ERROR.text("error").to_string(), "\x1b[31;1merror\x1b[39;22m");
+///```
+#[allow(clippy::module_name_repetitions)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SGRStyle {
+    /// The type of clean, refer to [`SGRString::clean`]
+    pub clean: CleanKind,
+    /// Where padding is placed, refer to [`SGRString::pad`]
+    pub pad: PadKind,
+
+    /// Any custom codes written before the text
+    pub custom_places: CustomCodes,
+    /// Any custom codes written after the text
+    pub custom_cleans: CustomCodes,
+
+    /// The color of the foreground, refer to [`SGRString::foreground`]
+    pub foreground: ColorKind,
+    /// The color of the background, refer to [`SGRString::background`]
+    pub background: ColorKind,
+    /// The color of the underline, refer to [`SGRString::underline_color`]
+    pub underline_color: ColorKind,
+
+    /// Refer to [`SGRString::reset`]
+    pub reset: bool,
+    /// Refer to [`StyleKind`]
+    pub bold: StyleKind,
+    /// Refer to [`StyleKind`]
+    pub dim: StyleKind,
+    /// Refer to [`StyleKind`]
+    pub italic: StyleKind,
+    /// Refer to [`StyleKind`]
+    pub underline: StyleKind,
+    /// The style of the underline, refer to [`UnderlineKind`]
+    pub underline_style: UnderlineKind,
+    /// Refer to [`StyleKind`]
+    pub blinking: StyleKind,
+    /// Refer to [`StyleKind`]
+    pub inverse: StyleKind,
+    /// Refer to [`StyleKind`]
+    pub hidden: StyleKind,
+    /// Refer to [`StyleKind`]
+    pub strikethrough: StyleKind,
+    /// Refer to [`StyleKind`]
+    pub doubly_underlined: StyleKind,
+    /// Refer to [`StyleKind`]
+    pub proportional_spacing: StyleKind,
+    /// Refer to [`StyleKind`]
+    pub framed: StyleKind,
+    /// Refer to [`StyleKind`]
+    pub encircled: StyleKind,
+    /// Refer to [`StyleKind`]
+    pub overline: StyleKind,
+    /// Refer to [`StyleKind`]
+    pub superscript: StyleKind,
+    /// Refer to [`StyleKind`]
+    pub subscript: StyleKind,
+    /// The font used, refer to [`FontKind`]
+    pub font: FontKind,
+    /// The ideogram style, refer to [`IdeogramKind`]
+    pub ideogram: IdeogramKind,
+}
+impl SGRStyle {
+    /// The amount of custom codes that can be placed, and cleaned
+    pub const CUSTOM_CAPACITY: usize = CustomCodes::CAPACITY;
+    /// Creates a new style, where nothing is applied
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            clean: CleanKind::None,
+            pad: PadKind::Inside,
+            custom_places: CustomCodes::new(),
+            custom_cleans: CustomCodes::new(),
+            foreground: ColorKind::None,
+            background: ColorKind::None,
+            underline_color: ColorKind::None,
+            reset: false,
+            bold: StyleKind::None,
+            dim: StyleKind::None,
+            italic: StyleKind::None,
+            underline: StyleKind::None,
+            underline_style: UnderlineKind::None,
+            blinking: StyleKind::None,
+            inverse: StyleKind::None,
+            hidden: StyleKind::None,
+            strikethrough: StyleKind::None,
+            doubly_underlined: StyleKind::None,
+            proportional_spacing: StyleKind::None,
+            framed: StyleKind::None,
+            encircled: StyleKind::None,
+            overline: StyleKind::None,
+            superscript: StyleKind::None,
+            subscript: StyleKind::None,
+            font: FontKind::None,
+            ideogram: IdeogramKind::None,
+        }
+    }
+    /// Adds a style, the same way as [`EasySGR::style`](crate::EasySGR::style)
+    #[must_use]
+    pub const fn style(mut self, style: Style) -> Self {
+        match style.change() {
+            StyleChange::Reset => self.reset = true,
+            StyleChange::Style(i, kind) => *self.styles_mut()[i] = kind,
+            StyleChange::Underline(kind) => self.underline_style = kind,
+            StyleChange::Font(kind) => self.font = kind,
+            StyleChange::Ideogram(kind) => self.ideogram = kind,
+        }
+        self
+    }
+    /// Adds a color(foreground, background or underline),
+    /// the same way as [`EasySGR::color`](crate::EasySGR::color)
+    #[must_use]
+    #[allow(clippy::needless_pass_by_value)]
+    pub const fn color(mut self, color: Color) -> Self {
+        let (kind, layer) = color.split();
+        match layer {
+            Layer::Foreground => self.foreground = kind,
+            Layer::Background => self.background = kind,
+            Layer::Underline => self.underline_color = kind,
+        }
+        self
+    }
+    /// Sets the [`CleanKind`] variant
+    #[must_use]
+    pub const fn clean(mut self, clean: CleanKind) -> Self {
+        self.clean = clean;
+        self
+    }
+    /// Sets the [`PadKind`] variant
+    #[must_use]
+    pub const fn pad(mut self, pad: PadKind) -> Self {
+        self.pad = pad;
+        self
+    }
+    /// Adds a custom code to be written before the text
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`SGRStyle::CUSTOM_CAPACITY`] codes are already placed,
+    /// failing compilation when used in a `const`
+    #[must_use]
+    pub const fn custom_place(mut self, code: u8) -> Self {
+        self.custom_places = self.custom_places.with(code);
+        self
+    }
+    /// Adds a custom code to be written after the text
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`SGRStyle::CUSTOM_CAPACITY`] codes are already cleaned,
+    /// failing compilation when used in a `const`
+    #[must_use]
+    pub const fn custom_clean(mut self, code: u8) -> Self {
+        self.custom_cleans = self.custom_cleans.with(code);
+        self
+    }
+    /// Returns the escape placing the style, equivalent to [`SGRString::place_all`]
+    ///
+    /// Extended colors are written using [`ColorEncoding::Semicolon`].
+    /// Being computed ahead of time, whether SGR codes are
+    /// [enabled](crate::set_sgr_enabled) is not respected by the escape itself,
+    /// only by its [`Display`] implementation
+    #[must_use]
+    pub const fn place_escape(&self) -> SGREscape {
+        let mut escape = SGREscape::new();
+        if self.reset {
+            escape.write_code(0);
+        }
+        escape.write_color(&self.foreground, 30, 90, 38, 39);
+        escape.write_color(&self.background, 40, 100, 48, 49);
+        match self.underline_color {
+            ColorKind::None => (),
+            ColorKind::Default => escape.write_code(59),
+            ColorKind::Rgb(r, g, b) => escape.write_param(Param::Rgb(58, r, g, b)),
+            ref kind => escape.write_param(Param::Byte(
+                58,
+                match kind.index() {
+                    Some(n) => n,
+                    None => 0,
+                },
+            )),
+        }
+        let styles = self.styles();
+        let mut i = 0;
+        while i < styles.len() {
+            let (place, not) = STYLE_CODES[i];
+            match styles[i] {
+                StyleKind::None => (),
+                StyleKind::Place => escape.write_code(place),
+                StyleKind::Clean => escape.write_code(not),
+            }
+            i += 1;
+        }
+        if let Some(sub) = self.underline_style.sub() {
+            escape.write_param(Param::Sub(4, sub));
+        }
+        if let Some(code) = self.font.code() {
+            escape.write_code(code);
+        }
+        if let Some(code) = self.ideogram.code() {
+            escape.write_code(code);
+        }
+        escape.write_codes(self.custom_places.as_slice());
+        escape.finish()
+    }
+    /// Returns the escape cleaning the style, equivalent to [`SGRString::clean_all`]
+    ///
+    /// Like [`SGRStyle::place_escape`], is able to be computed at compile time
+    #[must_use]
+    pub const fn clean_escape(&self) -> SGREscape {
+        let mut escape = SGREscape::new();
+        match self.clean {
+            CleanKind::Reset => escape.write_code(0),
+            CleanKind::Reverse => {
+                if !matches!(self.foreground, ColorKind::None) {
+                    escape.write_code(39);
+                }
+                if !matches!(self.background, ColorKind::None) {
+                    escape.write_code(49);
+                }
+                if !matches!(self.underline_color, ColorKind::None) {
+                    escape.write_code(59);
+                }
+                let styles = self.styles();
+                let mut i = 0;
+                while i < styles.len() {
+                    let (place, not) = STYLE_CODES[i];
+                    match styles[i] {
+                        StyleKind::None => (),
+                        StyleKind::Place => escape.write_code(not),
+                        StyleKind::Clean => escape.write_code(place),
+                    }
+                    i += 1;
+                }
+                if !matches!(self.underline_style, UnderlineKind::None) {
+                    escape.write_code(24);
+                }
+                if !matches!(self.font, FontKind::None | FontKind::Primary) {
+                    escape.write_code(10);
+                }
+                if !matches!(self.ideogram, IdeogramKind::None | IdeogramKind::Clean) {
+                    escape.write_code(65);
+                }
+            }
+            CleanKind::None => (),
+        }
+        escape.write_codes(self.custom_cleans.as_slice());
+        escape.finish()
+    }
+    /// Lists every attribute held as a [`StyleKind`], in the order of [`STYLE_CODES`]
+    const fn styles(&self) -> [&StyleKind; STYLE_CODES.len()] {
+        [
+            &self.bold,
+            &self.dim,
+            &self.italic,
+            &self.underline,
+            &self.blinking,
+            &self.inverse,
+            &self.hidden,
+            &self.strikethrough,
+            &self.doubly_underlined,
+            &self.proportional_spacing,
+            &self.framed,
+            &self.encircled,
+            &self.overline,
+            &self.superscript,
+            &self.subscript,
+        ]
+    }
+    /// Lists every attribute held as a [`StyleKind`], in the order of [`STYLE_CODES`]
+    const fn styles_mut(&mut self) -> [&mut StyleKind; STYLE_CODES.len()] {
+        [
+            &mut self.bold,
+            &mut self.dim,
+            &mut self.italic,
+            &mut self.underline,
+            &mut self.blinking,
+            &mut self.inverse,
+            &mut self.hidden,
+            &mut self.strikethrough,
+            &mut self.doubly_underlined,
+            &mut self.proportional_spacing,
+            &mut self.framed,
+            &mut self.encircled,
+            &mut self.overline,
+            &mut self.superscript,
+            &mut self.subscript,
+        ]
+    }
+}
+impl Default for SGRStyle {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl Display for SGRStyle {
+    /// Writes the escape placing the style
+    #[cfg(not(feature = "partial"))]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.place_escape().fmt(f)
+    }
+    /// Writes the codes placing the style, without the sequence escape & end
+    #[cfg(feature = "partial")]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if crate::is_sgr_enabled() {
+            f.write_str(self.place_escape().codes())
+        } else {
+            Ok(())
+        }
+    }
+}
+#[cfg(feature = "alloc")]
+impl From<&SGRStyle> for SGRString {
+    fn from(value: &SGRStyle) -> Self {
+        Self {
+            text: alloc::string::String::new(),
+            clean: value.clean.clone(),
+            pad: value.pad.clone(),
+            custom_places: value.custom_places.as_slice().into(),
+            custom_cleans: value.custom_cleans.as_slice().into(),
+            foreground: value.foreground.clone(),
+            background: value.background.clone(),
+            underline_color: value.underline_color.clone(),
+            reset: value.reset,
+            bold: value.bold.clone(),
+            dim: value.dim.clone(),
+            italic: value.italic.clone(),
+            underline: value.underline.clone(),
+            underline_style: value.underline_style.clone(),
+            blinking: value.blinking.clone(),
+            inverse: value.inverse.clone(),
+            hidden: value.hidden.clone(),
+            strikethrough: value.strikethrough.clone(),
+            doubly_underlined: value.doubly_underlined.clone(),
+            proportional_spacing: value.proportional_spacing.clone(),
+            framed: value.framed.clone(),
+            encircled: value.encircled.clone(),
+            overline: value.overline.clone(),
+            superscript: value.superscript.clone(),
+            subscript: value.subscript.clone(),
+            font: value.font.clone(),
+            ideogram: value.ideogram.clone(),
+        }
+    }
+}
+#[cfg(feature = "alloc")]
+impl From<SGRStyle> for SGRString {
+    fn from(value: SGRStyle) -> Self {
+        Self::from(&value)
+    }
+}
+/// Component of [`SGRStyle`]; a fixed amount of custom codes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CustomCodes {
+    /// The codes, of which only the first `len` are in use
+    codes: [u8; Self::CAPACITY],
+    /// The amount of codes in use
+    len: usize,
+}
+impl CustomCodes {
+    /// The amount of codes that fit
+    pub const CAPACITY: usize = 8;
+    /// Creates an empty set of codes
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            codes: [0; Self::CAPACITY],
+            len: 0,
+        }
+    }
+    /// Returns the codes with the given code added
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`CustomCodes::CAPACITY`] codes are already held
+    #[must_use]
+    pub const fn with(mut self, code: u8) -> Self {
+        assert!(self.len < Self::CAPACITY, "too many custom codes");
+        self.codes[self.len] = code;
+        self.len += 1;
+        self
+    }
+    /// Returns the codes held
+    #[must_use]
+    pub const fn as_slice(&self) -> &[u8] {
+        self.codes.split_at(self.len).0
+    }
+}
+impl Default for CustomCodes {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+/// A complete SGR escape, computed by an [`SGRStyle`]
+///
+/// Empty when there are no codes to write
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SGREscape {
+    /// The written escape
+    buf: CodeBuffer<{ Self::CAPACITY }>,
+}
+impl SGREscape {
+    /// The most bytes an escape is made of
+    const CAPACITY: usize = 256;
+    /// The length of the sequence escape, `\x1b[`
+    const ESC_LEN: usize = 2;
+    /// Creates an escape holding only the sequence escape
+    const fn new() -> Self {
+        let mut buf = CodeBuffer::new();
+        buf.push("\x1b[");
+        Self { buf }
+    }
+    /// Returns the escape as a [`str`]
+    ///
+    /// When called in a `const`, the returned [`str`] is `'static`
+    #[must_use]
+    pub const fn as_str(&self) -> &str {
+        self.buf.as_str()
+    }
+    /// Returns the codes of the escape, without the sequence escape & end
+    #[cfg(feature = "partial")]
+    fn codes(&self) -> &str {
+        let s = self.as_str();
+        s.get(Self::ESC_LEN..s.len().saturating_sub(1))
+            .unwrap_or_default()
+    }
+    /// Writes a parameter, separating it from the previous one
+    const fn write_param(&mut self, param: Param) {
+        if self.buf.len() != Self::ESC_LEN {
+            self.buf.push(";");
+        }
+        param.encode_into(&mut self.buf, ColorEncoding::Semicolon);
+    }
+    /// Writes a code, separating it from the previous one
+    const fn write_code(&mut self, code: u8) {
+        self.write_param(Param::Code(code));
+    }
+    /// Writes codes, separating them from the previous one
+    const fn write_codes(&mut self, codes: &[u8]) {
+        let mut i = 0;
+        while i < codes.len() {
+            self.write_code(codes[i]);
+            i += 1;
+        }
+    }
+    /// Writes a color of the given kind,
+    /// using the given codes for its basic, bright, extended & default colors
+    const fn write_color(&mut self, kind: &ColorKind, basic: u8, bright: u8, ext: u8, not: u8) {
+        match *kind {
+            ColorKind::None => (),
+            ColorKind::Default => self.write_code(not),
+            ColorKind::Byte(n) => self.write_param(Param::Byte(ext, n)),
+            ColorKind::Rgb(r, g, b) => self.write_param(Param::Rgb(ext, r, g, b)),
+            ref kind => match kind.index() {
+                Some(n) if n < 8 => self.write_code(basic + n),
+                Some(n) => self.write_code(bright + n - 8),
+                None => (),
+            },
+        }
+    }
+    /// Writes the sequence end, or empties the escape if no codes were written
+    const fn finish(mut self) -> Self {
+        if self.buf.len() == Self::ESC_LEN {
+            self.buf.truncate(0);
+        } else {
+            self.buf.push("m");
+        }
+        self
+    }
+}
+impl Display for SGREscape {
+    /// Writes the escape, unless SGR codes are [disabled](crate::set_sgr_enabled)
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        if crate::is_sgr_enabled() {
+            f.write_str(self.as_str())
+        } else {
+            Ok(())
+        }
+    }
+}
+/// Component of [`SGRString`]; the type of clean
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum CleanKind {
+    /// Does nothing
+    #[default]
+    None,
+    /// Resets all by writing `\x1b[0m`
+    Reset,
+    /// Undoes the effects of the [`SGRString::place_all`].
+    Reverse,
+}
+/// Component of [`SGRString`]; where padding is placed
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum PadKind {
+    /// Pads within the SGR codes, so the padding is styled
+    #[default]
+    Inside,
+    /// Pads outside of the SGR codes, so the padding is left unstyled
+    Outside,
+}
+/// Component of [`SGRString`]; the type of a style
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum StyleKind {
+    /// Do nothing
+    #[default]
+    None,
+    /// Apply the style
+    Place,
+    /// Apply what undoes the style
+    ///
+    /// The equivalent in [`Style`] are variants prefixed with `Not`
+    Clean,
+}
+/// Component of [`SGRString`]; the style of an underline
+///
+/// Written as the sub-parameter of the underline code, i.e. `4:3`,
+/// which is supported by most modern terminals
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum UnderlineKind {
+    /// Do nothing
+    #[default]
+    None,
+    /// A single straight underline, `4:1`
+    Single,
+    /// A double underline, `4:2`
+    Double,
+    /// A curly underline, `4:3`
+    Curly,
+    /// A dotted underline, `4:4`
+    Dotted,
+    /// A dashed underline, `4:5`
+    Dashed,
+}
+impl UnderlineKind {
+    /// Returns the sub-parameter written after the underline code
+    ///
+    /// Returns `None` for [`UnderlineKind::None`]
+    #[must_use]
+    pub const fn sub(&self) -> Option<u8> {
+        match self {
+            Self::None => None,
+            Self::Single => Some(1),
+            Self::Double => Some(2),
+            Self::Curly => Some(3),
+            Self::Dotted => Some(4),
+            Self::Dashed => Some(5),
+        }
+    }
+}
+/// Component of [`SGRString`]; the font used
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum FontKind {
+    /// Do nothing
+    #[default]
+    None,
+    /// The primary, or default, font, `10`
+    ///
+    /// Undoes the other fonts
+    Primary,
+    /// An alternate font, `11`-`19`
+    ///
+    /// Holds the font's number, `1`-`9`, numbers outside of which are clamped
+    Alternate(u8),
+    /// A Fraktur, or blackletter, font, `20`
+    Fraktur,
+}
+impl FontKind {
+    /// Returns the code of the font
+    ///
+    /// Returns `None` for [`FontKind::None`]
+    #[must_use]
+    pub const fn code(&self) -> Option<u8> {
+        match *self {
+            Self::None => None,
+            Self::Primary => Some(10),
+            Self::Alternate(n) => Some(
+                10 + if n < 1 {
+                    1
+                } else if n > 9 {
+                    9
+                } else {
+                    n
+                },
+            ),
+            Self::Fraktur => Some(20),
+        }
+    }
+}
+/// Component of [`SGRString`]; the ideogram style
+///
+/// Rarely supported by terminals
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum IdeogramKind {
+    /// Do nothing
+    #[default]
+    None,
+    /// An ideogram underline or right side line, `60`
+    Underline,
+    /// An ideogram double underline or double right side line, `61`
+    DoubleUnderline,
+    /// An ideogram overline or left side line, `62`
+    Overline,
+    /// An ideogram double overline or double left side line, `63`
+    DoubleOverline,
+    /// An ideogram stress marking, `64`
+    Stress,
+    /// Undoes all ideogram styles, `65`
+    Clean,
+}
+impl IdeogramKind {
+    /// Returns the code of the ideogram style
+    ///
+    /// Returns `None` for [`IdeogramKind::None`]
+    #[must_use]
+    pub const fn code(&self) -> Option<u8> {
+        match self {
+            Self::None => None,
+            Self::Underline => Some(60),
+            Self::DoubleUnderline => Some(61),
+            Self::Overline => Some(62),
+            Self::DoubleOverline => Some(63),
+            Self::Stress => Some(64),
+            Self::Clean => Some(65),
+        }
+    }
+}
+/// Component of [`SGRString`]; the type of color
+///
+/// Used for both foreground and background
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[allow(missing_docs)]
+pub enum ColorKind {
+    /// Does nothing
+    #[default]
+    None,
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    BrightBlack,
+    BrightRed,
+    BrightGreen,
+    BrightYellow,
+    BrightBlue,
+    BrightMagenta,
+    BrightCyan,
+    BrightWhite,
+    Byte(u8),
+    Rgb(u8, u8, u8),
+    /// Applies the default `SGR` color
+    Default,
+}
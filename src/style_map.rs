@@ -0,0 +1,170 @@
+use core::{error::Error, fmt::Display, str::FromStr};
+#[cfg(feature = "std")]
+use std::env;
+
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::String,
+};
+
+use crate::{CleanKind, EasySGR, Painted, ParseCodesError, SGRString};
+
+/// Styles read from a `:` separated list of `key=codes` entries
+///
+/// The format used by environment variables such as `GREP_COLORS` & `GCC_COLORS`,
+/// where `codes` are the parameters of an SGR sequence as read by [`SGRString::from_codes`].
+/// Entries without an `=` are read as flags, such as the `rv` & `ne` of `GREP_COLORS`.
+///
+/// Every style is cleaned by [`CleanKind::Reset`], as these tools do
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{Color::*, EasySGR, StyleMap, Style::*};
+///
+///let mut colors = StyleMap::new();
+///colors.set("error", Bold.color(RedFg));
+///colors.load("error=01;35:note=01;36:ne").unwrap();
+///
+///assert_eq!(colors.paint("error", "error:").to_string(), "\x1b[35;1merror:\x1b[0m");
+///assert!(colors.has_flag("ne"));
+///```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StyleMap {
+    /// The style of each key
+    styles: BTreeMap<String, SGRString>,
+    /// The keys read without an `=`
+    flags: BTreeSet<String>,
+}
+impl StyleMap {
+    /// Creates a new, empty [`StyleMap`]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            styles: BTreeMap::new(),
+            flags: BTreeSet::new(),
+        }
+    }
+    /// Loads the environment variable of the given name, see [`StyleMap::load`]
+    ///
+    /// Does nothing if the variable is not set
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the variable is not valid, see [`StyleMap::load`]
+    #[cfg(feature = "std")]
+    pub fn load_env(&mut self, var: &str) -> Result<(), ParseStyleMapError> {
+        env::var(var).map_or(Ok(()), |s| self.load(&s))
+    }
+    /// Loads the entries of the given string, overriding the styles already set
+    ///
+    /// Empty entries are skipped
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if an entry has no key or invalid codes,
+    /// in which case no entries are loaded
+    pub fn load(&mut self, s: &str) -> Result<(), ParseStyleMapError> {
+        let mut loaded = self.clone();
+        for entry in s.split(':').filter(|entry| !entry.is_empty()) {
+            let Some((key, codes)) = entry.split_once('=') else {
+                loaded.flags.insert(String::from(entry));
+                continue;
+            };
+            if key.is_empty() {
+                return Err(ParseStyleMapError::Key(String::from(entry)));
+            }
+            let style = SGRString::from_codes(codes)
+                .map_err(|e| ParseStyleMapError::Codes(String::from(key), e))?;
+            loaded.set(key, style);
+        }
+        *self = loaded;
+        Ok(())
+    }
+    /// Returns the style of the given key, if it has one
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<&SGRString> {
+        self.styles.get(key)
+    }
+    /// Styles the given value with the style of the given key
+    ///
+    /// The value is left unstyled if the key has no style
+    pub fn paint<'a, T: Display>(&'a self, key: &str, inner: T) -> Painted<'a, T> {
+        Painted::or_plain(self.get(key), inner)
+    }
+    /// Whether the given key was read as a flag, without an `=`
+    #[must_use]
+    pub fn has_flag(&self, key: &str) -> bool {
+        self.flags.contains(key)
+    }
+    /// Sets the style of the given key, returning its previous style
+    ///
+    /// The style is cleaned by [`CleanKind::Reset`]
+    pub fn set(
+        &mut self,
+        key: impl Into<String>,
+        style: impl Into<SGRString>,
+    ) -> Option<SGRString> {
+        self.styles
+            .insert(key.into(), style.clean(CleanKind::Reset))
+    }
+    /// Returns an iterator over every key & its style, ordered by key
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &SGRString)> {
+        self.styles.iter().map(|(key, style)| (key.as_str(), style))
+    }
+    /// Returns an iterator over every flag, ordered by key
+    pub fn flags(&self) -> impl Iterator<Item = &str> {
+        self.flags.iter().map(String::as_str)
+    }
+}
+impl FromStr for StyleMap {
+    type Err = ParseStyleMapError;
+
+    /// Loads an empty [`StyleMap`], see [`StyleMap::load`]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut map = Self::new();
+        map.load(s)?;
+        Ok(map)
+    }
+}
+impl Display for StyleMap {
+    /// Writes every style then every flag, able to be loaded again
+    ///
+    /// Only the codes placing each style are written
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut sep = "";
+        for (key, style) in self.iter() {
+            write!(f, "{sep}{key}={}", style.to_codes())?;
+            sep = ":";
+        }
+        for flag in self.flags() {
+            write!(f, "{sep}{flag}")?;
+            sep = ":";
+        }
+        Ok(())
+    }
+}
+/// An error encountered while trying to parse a [`StyleMap`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseStyleMapError {
+    /// An entry without a key before its `=`
+    Key(String),
+    /// A key with invalid codes, see [`SGRString::from_codes`]
+    Codes(String, ParseCodesError),
+}
+impl Display for ParseStyleMapError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Key(entry) => write!(f, "Missing key in entry: {entry}"),
+            Self::Codes(key, e) => write!(f, "{e} for key: {key}"),
+        }
+    }
+}
+impl Error for ParseStyleMapError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Codes(_, e) => Some(e),
+            Self::Key(_) => None,
+        }
+    }
+}
@@ -0,0 +1,182 @@
+use core::{error::Error, fmt::Display, str::FromStr};
+
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
+
+use crate::{CleanKind, Color::*, EasySGR, Painted, ParseCodesError, SGRString, Style::*};
+
+/// A set of styles, each given to a semantic role such as `error` or `path`
+///
+/// Lets styles be looked up by what they are used for, so they can be
+/// changed in one place or customised by users without recompiling.
+///
+/// [`Theme::default`] holds the built-in roles, see [`Theme::ROLES`],
+/// while [`Theme::new`] holds none.
+///
+/// Themes are loaded from, and formatted as, lines of `role = codes`,
+/// where `codes` are the parameters of an SGR sequence as read by [`SGRString::from_codes`].
+/// Blank lines & lines starting with `#` are ignored
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::Theme;
+///
+///let mut theme = Theme::default();
+///theme.load("# user overrides\nerror = 1;38;5;196\n").unwrap();
+///
+///assert_eq!(
+///    theme.paint("error", "failed").to_string(),
+///    "\x1b[38;5;196;1mfailed\x1b[39;22m"
+///);
+///assert_eq!(theme.paint("unknown", "plain").to_string(), "plain");
+///```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Theme {
+    /// The style of each role
+    styles: BTreeMap<String, SGRString>,
+}
+impl Theme {
+    /// The roles of the built-in theme
+    pub const ROLES: [&'static str; 9] = [
+        "error", "warning", "info", "success", "hint", "emphasis", "path", "key", "value",
+    ];
+    /// Creates a new, empty theme
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            styles: BTreeMap::new(),
+        }
+    }
+    /// Returns the style of the given role, if it has one
+    #[must_use]
+    pub fn get(&self, role: &str) -> Option<&SGRString> {
+        self.styles.get(role)
+    }
+    /// Styles the given value with the style of the given role
+    ///
+    /// The value is left unstyled if the role has no style
+    pub fn paint<'a, T: Display>(&'a self, role: &str, inner: T) -> Painted<'a, T> {
+        Painted::or_plain(self.get(role), inner)
+    }
+    /// Sets the style of the given role, returning its previous style
+    ///
+    /// The text of the style is ignored when it is used
+    pub fn set(
+        &mut self,
+        role: impl Into<String>,
+        style: impl Into<SGRString>,
+    ) -> Option<SGRString> {
+        self.styles.insert(role.into(), style.into())
+    }
+    /// Removes the style of the given role, returning it
+    pub fn remove(&mut self, role: &str) -> Option<SGRString> {
+        self.styles.remove(role)
+    }
+    /// Returns an iterator over every role & its style, ordered by role
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &SGRString)> {
+        self.styles
+            .iter()
+            .map(|(role, style)| (role.as_str(), style))
+    }
+    /// Loads the roles of the given text, replacing the styles of roles already set
+    ///
+    /// Loaded styles are cleaned by [`CleanKind::Reverse`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a line is not a valid `role = codes` pair,
+    /// in which case no roles are loaded
+    pub fn load(&mut self, s: &str) -> Result<(), ParseThemeError> {
+        let mut loaded = Vec::new();
+        for (i, line) in s.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (role, codes) = line
+                .split_once('=')
+                .ok_or(ParseThemeError::Separator(i + 1))?;
+            let role = role.trim();
+            if role.is_empty() {
+                return Err(ParseThemeError::Role(i + 1));
+            }
+            let style = SGRString::from_codes(codes.trim())
+                .map_err(|e| ParseThemeError::Codes(i + 1, e))?;
+            loaded.push((role, style.clean(CleanKind::Reverse)));
+        }
+        for (role, style) in loaded {
+            self.set(role, style);
+        }
+        Ok(())
+    }
+}
+impl Default for Theme {
+    /// Creates the built-in theme
+    fn default() -> Self {
+        let mut theme = Self::new();
+        for (role, style) in [
+            ("error", Bold.color(RedFg)),
+            ("warning", Bold.color(YellowFg)),
+            ("info", Bold.color(BlueFg)),
+            ("success", Bold.color(GreenFg)),
+            ("hint", Dim.into()),
+            ("emphasis", Bold.into()),
+            ("path", CyanFg.style(Underline)),
+            ("key", BlueFg.into()),
+            ("value", GreenFg.into()),
+        ] {
+            theme.set(role, style.clean(CleanKind::Reverse));
+        }
+        theme
+    }
+}
+impl FromStr for Theme {
+    type Err = ParseThemeError;
+
+    /// Loads an empty theme, see [`Theme::load`]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut theme = Self::new();
+        theme.load(s)?;
+        Ok(theme)
+    }
+}
+impl Display for Theme {
+    /// Writes every role as a line of `role = codes`, able to be loaded again
+    ///
+    /// Only the codes placing each style are written
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        for (role, style) in self.iter() {
+            writeln!(f, "{role} = {}", style.to_codes())?;
+        }
+        Ok(())
+    }
+}
+/// An error encountered while trying to load a [`Theme`]
+///
+/// Each variant holds the line the error is on, starting from `1`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseThemeError {
+    /// A line without an `=`
+    Separator(usize),
+    /// A line without a role before its `=`
+    Role(usize),
+    /// A line with invalid codes, see [`SGRString::from_codes`]
+    Codes(usize, ParseCodesError),
+}
+impl Display for ParseThemeError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Separator(line) => write!(f, "Missing '=' on line {line}"),
+            Self::Role(line) => write!(f, "Missing role on line {line}"),
+            Self::Codes(line, e) => write!(f, "{e} on line {line}"),
+        }
+    }
+}
+impl Error for ParseThemeError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            Self::Codes(_, e) => Some(e),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,138 @@
+use core::cmp::Ordering;
+
+use alloc::string::String;
+
+use crate::{parsing::find_sequence, SGRString};
+
+/// Unicode tables used to find the width of a [`char`]
+mod tables;
+
+/// The zero width joiner, used to join characters into a single grapheme
+const ZWJ: char = '\u{200D}';
+/// Variation selector 16, used to request a character's emoji presentation
+const VS16: char = '\u{FE0F}';
+
+/// Returns the width of the given [`str`] when displayed in a terminal
+///
+/// SGR sequences are skipped over, and so do not count towards the width.
+/// Wide characters, such as CJK characters & emoji, are counted as two columns,
+/// while combining marks, control characters & joined emoji count as zero.
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::display_width;
+///
+///assert_eq!(display_width("\x1b[31mred\x1b[0m"), 3);
+///assert_eq!(display_width("日本"), 4);
+///assert_eq!(display_width("e\u{301}"), 1);
+///```
+#[must_use]
+pub fn display_width(s: &str) -> usize {
+    let mut width = 0;
+    let mut rest = s;
+    while let Some((start, end)) = find_sequence(rest) {
+        width += text_width(&rest[..start]);
+        rest = &rest[end..];
+    }
+    width + text_width(rest)
+}
+impl SGRString {
+    /// Returns the width of [`SGRString::text`] when displayed in a terminal
+    ///
+    /// The SGR codes of the [`SGRString`] take up no width.
+    /// See [`display_width`]
+    #[must_use]
+    pub fn display_width(&self) -> usize {
+        display_width(&self.text)
+    }
+}
+/// Returns the width of the given text, which should contain no SGR sequences
+fn text_width(s: &str) -> usize {
+    let mut counter = WidthCounter::default();
+    s.chars().map(|ch| counter.width_of(ch)).sum()
+}
+/// Returns the given [`str`] cut down to the given display width
+///
+/// SGR sequences are kept whole, and are never removed
+pub(crate) fn truncate(s: &str, width: usize) -> String {
+    let mut buf = String::with_capacity(s.len());
+    let mut counter = WidthCounter::default();
+    // becomes None once the text has been cut
+    let mut remaining = Some(width);
+    let mut truncate_text = |text: &str, buf: &mut String| {
+        for ch in text.chars() {
+            remaining = remaining.and_then(|n| n.checked_sub(counter.width_of(ch)));
+            if remaining.is_none() {
+                return;
+            }
+            buf.push(ch);
+        }
+    };
+    let mut rest = s;
+    while let Some((start, end)) = find_sequence(rest) {
+        truncate_text(&rest[..start], &mut buf);
+        buf.push_str(&rest[start..end]);
+        rest = &rest[end..];
+    }
+    truncate_text(rest, &mut buf);
+    buf
+}
+/// Counts the width of [`char`]s, keeping track of the graphemes they form
+#[derive(Debug, Default)]
+struct WidthCounter {
+    /// The width of the last grapheme's first char
+    last: usize,
+    /// Whether the next char is joined into the last emoji
+    joined: bool,
+}
+impl WidthCounter {
+    /// Returns the width the given [`char`] adds to the text counted so far
+    fn width_of(&mut self, ch: char) -> usize {
+        let ch_width = char_width(ch);
+        if ch == ZWJ {
+            self.joined = self.last == 2;
+            0
+        } else if self.joined && ch_width != 0 {
+            self.joined = false;
+            0
+        } else if ch == VS16 && self.last == 1 {
+            self.last = 2;
+            1
+        } else if ch_width != 0 && !(self.last == 2 && is_emoji_modifier(ch)) {
+            self.last = ch_width;
+            ch_width
+        } else {
+            0
+        }
+    }
+}
+/// Returns the width of a single [`char`]
+fn char_width(ch: char) -> usize {
+    match ch {
+        '\0'..='\u{1F}' | '\u{7F}'..='\u{9F}' => 0,
+        ' '..='~' => 1,
+        ch if in_table(tables::ZERO_WIDTH, ch) => 0,
+        ch if in_table(tables::WIDE, ch) => 2,
+        _ => 1,
+    }
+}
+/// Whether the given [`char`] is a skin tone modifier
+const fn is_emoji_modifier(ch: char) -> bool {
+    matches!(ch, '\u{1F3FB}'..='\u{1F3FF}')
+}
+/// Whether the given [`char`] is within one of the table's ranges
+fn in_table(table: &[(u32, u32)], ch: char) -> bool {
+    let ch = u32::from(ch);
+    table
+        .binary_search_by(|&(start, end)| {
+            if end < ch {
+                Ordering::Less
+            } else if start > ch {
+                Ordering::Greater
+            } else {
+                Ordering::Equal
+            }
+        })
+        .is_ok()
+}
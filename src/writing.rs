@@ -1,6 +1,16 @@
-use std::{fmt, io};
+use core::fmt::{self, Display};
+#[cfg(feature = "std")]
+use std::io;
 
-use crate::{DiscreteSGR, SGRString};
+#[cfg(feature = "alloc")]
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+
+use crate::{is_sgr_enabled, ColorSupport, DiscreteSGR};
+#[cfg(feature = "alloc")]
+use crate::{
+    parsing::{find_sgr, unfinished_sgr},
+    SGRState, SGRString,
+};
 
 /// An interface for an [`SGRWriter`] to work with
 ///
@@ -12,8 +22,8 @@ pub trait CapableWriter: Sized {
     type Writer;
     /// The type of error returned by trait methods
     ///
-    /// Will typically be [`std::io::Error`] or [`std::fmt::Error`]
-    type Error: std::error::Error;
+    /// Will typically be [`std::io::Error`] or [`core::fmt::Error`]
+    type Error: core::error::Error;
     /// Writes a [`str`] to the inner writer
     ///
     /// # Errors
@@ -23,12 +33,42 @@ pub trait CapableWriter: Sized {
     fn write(&mut self, s: &str) -> Result<(), Self::Error>;
     /// Returns the type specified by [`CapableWriter::Writer`]
     fn get_writer(self) -> Self::Writer;
+    /// Returns how extended colors are to be written
+    ///
+    /// By default [`ColorEncoding::Semicolon`]
+    #[inline]
+    fn color_encoding(&self) -> ColorEncoding {
+        ColorEncoding::default()
+    }
 }
 /// A Standard SGR writer
 #[derive(Debug, Clone)]
 pub struct SGRWriter<W: CapableWriter> {
     /// A writer capable of writing a [`str`]
     pub writer: W,
+    /// The colors the writer is allowed to write
+    ///
+    /// By default [`ColorSupport::TrueColor`], meaning colors are written unchanged.
+    /// Otherwise colors are downgraded to the closest supported color
+    pub color_support: ColorSupport,
+    /// Whether SGR codes are written
+    ///
+    /// When `false` all SGR writing methods do nothing,
+    /// while [`SGRWriter::write_inner`] still writes text.
+    ///
+    /// Defaults to the value of [`is_sgr_enabled`]
+    pub enabled: bool,
+    /// How extended colors are written
+    ///
+    /// By default [`ColorEncoding::Semicolon`], the most widely supported encoding
+    pub color_encoding: ColorEncoding,
+    /// The attributes in effect, if they are being tracked
+    #[cfg(feature = "alloc")]
+    state: Option<SGRState>,
+    /// The start of a sequence written through [`SGRWriter::write_inner`]
+    /// that is yet to be finished, kept while tracking
+    #[cfg(feature = "alloc")]
+    pending: String,
 }
 impl<W: CapableWriter> SGRWriter<W> {
     /// Writes a [`str`] to the inner writer
@@ -41,8 +81,83 @@ impl<W: CapableWriter> SGRWriter<W> {
     /// Error type specified by [`CapableWriter::Error`]
     #[inline]
     pub fn write_inner(&mut self, s: &str) -> Result<(), W::Error> {
+        #[cfg(feature = "alloc")]
+        if let Some(state) = &mut self.state {
+            let joined;
+            let mut rest = if self.pending.is_empty() {
+                s
+            } else {
+                joined = core::mem::take(&mut self.pending) + s;
+                joined.as_str()
+            };
+            while let Some((_, end, params)) = find_sgr(rest) {
+                state.apply(&params);
+                rest = &rest[end..];
+            }
+            self.pending.push_str(unfinished_sgr(rest));
+        }
         self.write(s)
     }
+    /// Sets whether the attributes in effect are tracked
+    ///
+    /// While tracked, SGR codes are only written when they change the
+    /// attributes in effect, see [`SGRState::diff`].
+    /// Sequences written through [`SGRWriter::write_inner`] are tracked as well,
+    /// including those split between several writes.
+    ///
+    /// Tracking starts from the default state, assuming nothing is in effect.
+    ///
+    ///```rust
+    ///use easy_sgr::{Color::*, SGRWriter, Style::*};
+    ///
+    ///let mut writer = SGRWriter::from(String::new());
+    ///writer.track_state(true);
+    ///writer.inline_sgr(&RedFg).unwrap();
+    ///writer.inline_sgr(&RedFg).unwrap();
+    ///writer.write_inner("red").unwrap();
+    ///writer.reset_if_dirty().unwrap();
+    ///
+    ///assert_eq!(writer.internal(), "\x1b[31mred\x1b[0m");
+    ///```
+    #[inline]
+    #[cfg(feature = "alloc")]
+    pub fn track_state(&mut self, track: bool) {
+        self.state = track.then(SGRState::default);
+        self.pending.clear();
+    }
+    /// Returns the attributes in effect, or `None` if they are not tracked
+    #[inline]
+    #[must_use]
+    #[cfg(feature = "alloc")]
+    pub const fn state(&self) -> Option<&SGRState> {
+        self.state.as_ref()
+    }
+    /// Writes a reset if any attributes may be in effect
+    ///
+    /// When the attributes are not [tracked](SGRWriter::track_state)
+    /// a reset is always written.
+    ///
+    /// Used to guarantee nothing is left in effect at the end of output
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    /// Error type specified by [`CapableWriter::Error`]
+    pub fn reset_if_dirty(&mut self) -> Result<(), W::Error> {
+        #[cfg(feature = "alloc")]
+        if self.state.as_ref() == Some(&SGRState::default()) {
+            return Ok(());
+        }
+        if !self.enabled {
+            return Ok(());
+        }
+        self.write("\x1b[0m")?;
+        #[cfg(feature = "alloc")]
+        if let Some(state) = &mut self.state {
+            *state = SGRState::default();
+        }
+        Ok(())
+    }
     /// Returns the internal writer
     ///
     /// Returns the type specified by [`CapableWriter::get_writer`]
@@ -55,6 +170,7 @@ impl<W: CapableWriter> SGRWriter<W> {
     ///
     /// Used for convenience
     #[inline]
+    #[cfg(feature = "alloc")]
     pub fn builder(&self) -> SGRBuilder {
         SGRBuilder::default()
     }
@@ -65,10 +181,17 @@ impl<W: CapableWriter> SGRWriter<W> {
     /// Returns an error if writing fails.
     /// Error type specified by [`CapableWriter::Error`]
     #[inline]
+    #[cfg(feature = "alloc")]
     pub fn place_sgr(&mut self, sgr: &SGRString) -> Result<(), W::Error> {
-        let mut builder = SGRBuilder::default();
-        sgr.place_all(&mut builder);
-        builder.write_to(self)
+        if InlineBuilder::fits(sgr.custom_places.len()) {
+            let mut builder = InlineBuilder::default();
+            sgr.place_all(&mut builder);
+            self.write_builder(&mut builder)
+        } else {
+            let mut builder = SGRBuilder::default();
+            sgr.place_all(&mut builder);
+            self.write_builder(&mut builder)
+        }
     }
     /// Writes the contained SGR codes to the writer through calling [`SGRString::clean_all`]
     ///
@@ -79,10 +202,50 @@ impl<W: CapableWriter> SGRWriter<W> {
     /// Returns an error if writing fails.
     /// Error type specified by [`CapableWriter::Error`]
     #[inline]
+    #[cfg(feature = "alloc")]
     pub fn clean_sgr(&mut self, sgr: &SGRString) -> Result<(), W::Error> {
-        let mut builder = SGRBuilder::default();
-        sgr.clean_all(&mut builder);
-        builder.write_to(self)
+        if InlineBuilder::fits(sgr.custom_cleans.len()) {
+            let mut builder = InlineBuilder::default();
+            sgr.clean_all(&mut builder);
+            self.write_builder(&mut builder)
+        } else {
+            let mut builder = SGRBuilder::default();
+            sgr.clean_all(&mut builder);
+            self.write_builder(&mut builder)
+        }
+    }
+    /// Writes the contained SGR codes to the writer through calling [`SGRString::clean_all_within`]
+    ///
+    /// Supposed to reverse the effects made by [`SGRString::place_all`],
+    /// restoring the state of the enclosing `outer` [`SGRString`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    /// Error type specified by [`CapableWriter::Error`]
+    #[inline]
+    #[cfg(feature = "alloc")]
+    pub fn clean_sgr_within(&mut self, sgr: &SGRString, outer: &SGRString) -> Result<(), W::Error> {
+        if InlineBuilder::fits(sgr.custom_cleans.len()) {
+            let mut builder = InlineBuilder::default();
+            sgr.clean_all_within(outer, &mut builder);
+            self.write_builder(&mut builder)
+        } else {
+            let mut builder = SGRBuilder::default();
+            sgr.clean_all_within(outer, &mut builder);
+            self.write_builder(&mut builder)
+        }
+    }
+    /// Writes the codes transitioning between two states through calling [`SGRState::diff`]
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if writing fails.
+    /// Error type specified by [`CapableWriter::Error`]
+    #[inline]
+    #[cfg(feature = "alloc")]
+    pub fn diff_sgr(&mut self, prev: &SGRState, next: &SGRState) -> Result<(), W::Error> {
+        self.write_builder(&mut prev.diff(next))
     }
     /// Writes the contained SGR codes to the writer through calling [`DiscreteSGR::write`]
     ///
@@ -92,9 +255,9 @@ impl<W: CapableWriter> SGRWriter<W> {
     /// Error type specified by [`CapableWriter::Error`]
     #[inline]
     pub fn inline_sgr(&mut self, sgr: &impl DiscreteSGR) -> Result<(), W::Error> {
-        let mut builder = SGRBuilder::default();
+        let mut builder = InlineBuilder::default();
         sgr.write(&mut builder);
-        builder.write_to(self)
+        self.write_builder(&mut builder)
     }
     /// Writes the contained SGR codes to the writer
     ///
@@ -106,9 +269,15 @@ impl<W: CapableWriter> SGRWriter<W> {
     /// Returns an error if writing fails.
     /// Error type specified by [`CapableWriter::Error`]
     pub fn sgr(&mut self, sgr: &impl EasyWrite) -> Result<(), W::Error> {
-        let mut builder = SGRBuilder::default();
+        #[cfg(feature = "alloc")]
+        if !InlineBuilder::fits(sgr.custom_len()) {
+            let mut builder = SGRBuilder::default();
+            sgr.sgr(&mut builder);
+            return self.write_builder(&mut builder);
+        }
+        let mut builder = InlineBuilder::default();
         sgr.sgr(&mut builder);
-        builder.write_to(self)
+        self.write_builder(&mut builder)
     }
     /// Writes the contained SGR codes to the writer
     ///
@@ -123,28 +292,78 @@ impl<W: CapableWriter> SGRWriter<W> {
     /// Error type specified by [`CapableWriter::Error`]
     #[inline]
     pub fn partial_sgr(&mut self, sgr: &impl EasyWrite) -> Result<(), W::Error> {
-        let mut builder = SGRBuilder::default();
+        if !self.enabled {
+            return Ok(());
+        }
+        #[cfg(feature = "alloc")]
+        if !InlineBuilder::fits(sgr.custom_len()) {
+            let mut builder = SGRBuilder::default();
+            sgr.sgr(&mut builder);
+            return self.write_partial_builder(&mut builder);
+        }
+        let mut builder = InlineBuilder::default();
         sgr.sgr(&mut builder);
+        self.write_partial_builder(&mut builder)
+    }
+    /// Writes the codes of the given builder without the escape or end sequences,
+    /// downgrading them according to [`SGRWriter::color_support`]
+    fn write_partial_builder(&mut self, builder: &mut impl CapableBuilder) -> Result<(), W::Error> {
+        self.color_support.downgrade(builder);
+        #[cfg(feature = "alloc")]
+        if let Some(state) = &mut self.state {
+            state.apply(builder.params());
+        }
         builder.write_partial(self)
     }
+    /// Writes the codes of the given builder, downgrading them
+    /// according to [`SGRWriter::color_support`]
+    ///
+    /// When the state is tracked, only the codes changing it are written
+    ///
+    /// Does nothing if the writer is not [enabled](SGRWriter::enabled)
+    pub(crate) fn write_builder(
+        &mut self,
+        builder: &mut impl CapableBuilder,
+    ) -> Result<(), W::Error> {
+        if !self.enabled {
+            return Ok(());
+        }
+        self.color_support.downgrade(builder);
+        #[cfg(feature = "alloc")]
+        if let Some(state) = &mut self.state {
+            let mut next = state.clone();
+            let custom = next.apply_custom(builder.params());
+            let mut delta = state.diff(&next);
+            delta.write_codes(&custom);
+            *state = next;
+            return delta.write_to(self);
+        }
+        builder.write_to(self)
+    }
 }
 impl<W: CapableWriter> From<W> for SGRWriter<W> {
     fn from(value: W) -> Self {
-        Self { writer: value }
+        Self {
+            writer: value,
+            color_support: ColorSupport::default(),
+            enabled: is_sgr_enabled(),
+            color_encoding: ColorEncoding::default(),
+            #[cfg(feature = "alloc")]
+            state: None,
+            #[cfg(feature = "alloc")]
+            pending: String::new(),
+        }
     }
 }
-impl<W: std::fmt::Write> From<W> for SGRWriter<FmtWriter<W>> {
+impl<W: core::fmt::Write> From<W> for SGRWriter<FmtWriter<W>> {
     fn from(value: W) -> Self {
-        Self {
-            writer: FmtWriter(value),
-        }
+        Self::from(FmtWriter(value))
     }
 }
+#[cfg(feature = "std")]
 impl<W: std::io::Write> From<W> for SGRWriter<IoWriter<W>> {
     fn from(value: W) -> Self {
-        Self {
-            writer: IoWriter(value),
-        }
+        Self::from(IoWriter(value))
     }
 }
 impl<W: CapableWriter> CapableWriter for SGRWriter<W> {
@@ -154,14 +373,19 @@ impl<W: CapableWriter> CapableWriter for SGRWriter<W> {
     fn write(&mut self, s: &str) -> Result<(), Self::Error> {
         self.writer.write(s)
     }
-    #[must_use]
     fn get_writer(self) -> Self::Writer {
         self.writer
     }
+    #[inline]
+    fn color_encoding(&self) -> ColorEncoding {
+        self.color_encoding
+    }
 }
 /// Used to implement [`CapableWriter`] for [`std::io::Write`]
 #[derive(Debug, Clone)]
+#[cfg(feature = "std")]
 pub struct IoWriter<W: std::io::Write>(pub W);
+#[cfg(feature = "std")]
 impl<W: std::io::Write> CapableWriter for IoWriter<W> {
     type Writer = W;
     type Error = io::Error;
@@ -170,97 +394,450 @@ impl<W: std::io::Write> CapableWriter for IoWriter<W> {
         self.0.write_all(s.as_bytes())
     }
 
-    #[must_use]
     fn get_writer(self) -> Self::Writer {
         self.0
     }
 }
-/// Used to implement [`CapableWriter`] for [`std::fmt::Write`]
+/// Used to implement [`CapableWriter`] for [`core::fmt::Write`]
 #[derive(Debug, Clone)]
-pub struct FmtWriter<W: std::fmt::Write>(pub W);
-impl<W: std::fmt::Write> CapableWriter for FmtWriter<W> {
+pub struct FmtWriter<W: core::fmt::Write>(pub W);
+impl<W: core::fmt::Write> CapableWriter for FmtWriter<W> {
     type Writer = W;
     type Error = fmt::Error;
     #[inline]
     fn write(&mut self, s: &str) -> Result<(), Self::Error> {
         self.0.write_str(s)
     }
-    #[must_use]
     fn get_writer(self) -> Self::Writer {
         self.0
     }
 }
-/// Builds a SGR sequence
-#[derive(Debug, Default)]
-pub struct SGRBuilder(pub Vec<u8>);
-
-impl SGRBuilder {
-    /// Writes a code to the internal buffer
-    #[inline]
-    pub fn write_code(&mut self, code: u8) {
-        self.0.push(code);
+/// How extended colors, those of the 256 color palette & rgb colors, are written
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{Color::*, ColorEncoding, SGRWriter};
+///
+///let mut writer = SGRWriter::from(String::new());
+///writer.color_encoding = ColorEncoding::Colon;
+///writer.inline_sgr(&RgbFg(255, 0, 0)).unwrap();
+///
+///assert_eq!(writer.internal(), "\x1b[38:2::255:0:0m");
+///```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum ColorEncoding {
+    /// Separates the color's parts as parameters, i.e. `38;2;<r>;<g>;<b>`
+    ///
+    /// Understood by nearly all terminals
+    #[default]
+    Semicolon,
+    /// Separates the color's parts as sub-parameters, as specified by ITU T.416,
+    /// i.e. `38:2::<r>:<g>:<b>`
+    ///
+    /// Cannot be confused for other codes, though is not understood by some terminals
+    Colon,
+}
+/// A single parameter of an SGR sequence
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Param {
+    /// A code on its own, i.e. `1`
+    Code(u8),
+    /// A code followed by a colon separated sub-parameter, i.e. `4:3`
+    Sub(u8, u8),
+    /// A color of the 256 color palette, i.e. `38;5;<n>`
+    ///
+    /// Made of the color's code, `38`, `48` or `58`, then the color's index
+    Byte(u8, u8),
+    /// An rgb color, i.e. `38;2;<r>;<g>;<b>`
+    ///
+    /// Made of the color's code, `38`, `48` or `58`, then the red, green & blue values
+    Rgb(u8, u8, u8, u8),
+}
+impl Param {
+    /// Returns the parameter as it is written in a sequence,
+    /// with extended colors using the given encoding
+    ///
+    /// # Examples
+    ///
+    ///```rust
+    ///use easy_sgr::{ColorEncoding, Param};
+    ///
+    ///assert_eq!(Param::Byte(48, 208).encode(ColorEncoding::Semicolon), "48;5;208");
+    ///assert_eq!(Param::Byte(48, 208).encode(ColorEncoding::Colon), "48:5:208");
+    ///```
+    #[must_use]
+    #[cfg(feature = "alloc")]
+    pub fn encode(&self, encoding: ColorEncoding) -> String {
+        let mut buf: CodeBuffer = CodeBuffer::default();
+        self.encode_into(&mut buf, encoding);
+        buf.as_str().to_owned()
     }
-    /// Writes codes to the internal buffer
-    #[inline]
-    pub fn write_codes(&mut self, codes: &[u8]) {
-        self.0.extend_from_slice(codes);
+    /// The longest a parameter can be once written, i.e. `38:2::255:255:255`
+    const MAX_LEN: usize = 17;
+    /// The length of the parameter once written using [`ColorEncoding::Semicolon`]
+    #[cfg(feature = "alloc")]
+    pub(crate) const fn len(self) -> usize {
+        match self {
+            Self::Code(code) => digits(code),
+            Self::Sub(code, sub) => digits(code) + 1 + digits(sub),
+            Self::Byte(code, n) => digits(code) + 3 + digits(n),
+            Self::Rgb(code, r, g, b) => digits(code) + 5 + digits(r) + digits(g) + digits(b),
+        }
+    }
+    /// Formats the parameter into the given buffer
+    pub(crate) const fn encode_into<const N: usize>(
+        self,
+        buf: &mut CodeBuffer<N>,
+        encoding: ColorEncoding,
+    ) {
+        let sep = match encoding {
+            ColorEncoding::Semicolon => ";",
+            ColorEncoding::Colon => ":",
+        };
+        match self {
+            Self::Code(code) => buf.push_u8(code),
+            Self::Sub(code, sub) => {
+                buf.push_u8(code);
+                buf.push(":");
+                buf.push_u8(sub);
+            }
+            Self::Byte(code, n) => {
+                buf.push_u8(code);
+                buf.push(sep);
+                buf.push("5");
+                buf.push(sep);
+                buf.push_u8(n);
+            }
+            Self::Rgb(code, r, g, b) => {
+                buf.push_u8(code);
+                buf.push(sep);
+                buf.push("2");
+                buf.push(sep);
+                if matches!(encoding, ColorEncoding::Colon) {
+                    buf.push(sep);
+                }
+                buf.push_u8(r);
+                buf.push(sep);
+                buf.push_u8(g);
+                buf.push(sep);
+                buf.push_u8(b);
+            }
+        }
+    }
+}
+impl Display for Param {
+    /// Writes the parameter using [`ColorEncoding::Semicolon`]
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut buf: CodeBuffer = CodeBuffer::default();
+        self.encode_into(&mut buf, ColorEncoding::Semicolon);
+        f.write_str(buf.as_str())
+    }
+}
+/// The amount of decimal digits the given [`u8`] is written with
+#[cfg(feature = "alloc")]
+const fn digits(n: u8) -> usize {
+    match n {
+        0..=9 => 1,
+        10..=99 => 2,
+        100..=u8::MAX => 3,
+    }
+}
+/// A buffer on the stack that codes are formatted into
+///
+/// Avoids allocating a [`String`] for every code,
+/// letting a whole sequence be written at once
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct CodeBuffer<const N: usize = 128> {
+    /// The formatted bytes, only ever ascii
+    bytes: [u8; N],
+    /// The amount of bytes in use
+    len: usize,
+}
+impl<const N: usize> CodeBuffer<N> {
+    /// Creates an empty buffer
+    pub(crate) const fn new() -> Self {
+        Self {
+            bytes: [0; N],
+            len: 0,
+        }
     }
+    /// Appends a [`str`], which must fit in the remaining space
+    pub(crate) const fn push(&mut self, s: &str) {
+        let s = s.as_bytes();
+        let mut i = 0;
+        while i < s.len() {
+            self.bytes[self.len + i] = s[i];
+            i += 1;
+        }
+        self.len += s.len();
+    }
+    /// Appends a [`u8`] formatted as decimal digits
+    pub(crate) const fn push_u8(&mut self, mut n: u8) {
+        if n >= 10 {
+            if n >= 100 {
+                self.bytes[self.len] = b'0' + n / 100;
+                self.len += 1;
+                n %= 100;
+            }
+            self.bytes[self.len] = b'0' + n / 10;
+            self.len += 1;
+            n %= 10;
+        }
+        self.bytes[self.len] = b'0' + n;
+        self.len += 1;
+    }
+    /// Whether `len` more bytes fit in the buffer
+    const fn fits(&self, len: usize) -> bool {
+        self.len + len <= N
+    }
+    /// The amount of bytes in use
+    pub(crate) const fn len(&self) -> usize {
+        self.len
+    }
+    /// Shortens the buffer to the first `len` bytes
+    pub(crate) const fn truncate(&mut self, len: usize) {
+        if len < self.len {
+            self.len = len;
+        }
+    }
+    /// Returns the contents of the buffer
+    pub(crate) const fn as_str(&self) -> &str {
+        match core::str::from_utf8(self.bytes.split_at(self.len).0) {
+            Ok(s) => s,
+            Err(_) => "",
+        }
+    }
+    /// Writes the contents of the buffer, emptying it
+    fn flush<W: CapableWriter>(&mut self, writer: &mut W) -> Result<(), W::Error> {
+        if self.len != 0 {
+            writer.write(self.as_str())?;
+            self.len = 0;
+        }
+        Ok(())
+    }
+}
+impl<const N: usize> Default for CodeBuffer<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+/// An interface for the buffers SGR codes are written to
+///
+/// Implemented by [`SGRBuilder`], which allocates,
+/// and [`InlineBuilder`], which does not
+pub trait CapableBuilder {
+    /// Writes a parameter to the internal buffer
+    fn write_param(&mut self, param: Param);
+    /// Returns the buffered parameters
+    fn params(&self) -> &[Param];
+    /// Returns the buffered parameters, allowing them to be rewritten
+    fn params_mut(&mut self) -> &mut [Param];
+    /// Shortens the buffer to the first `len` parameters
+    fn truncate(&mut self, len: usize);
     /// Writes a code to the internal buffer
-    ///
-    /// Returns self to allow for chaining
     #[inline]
-    pub fn chain_code(&mut self, code: u8) -> &mut Self {
-        self.0.push(code);
-        self
+    fn write_code(&mut self, code: u8) {
+        self.write_param(Param::Code(code));
     }
     /// Writes codes to the internal buffer
+    #[inline]
+    fn write_codes(&mut self, codes: &[u8]) {
+        for &code in codes {
+            self.write_code(code);
+        }
+    }
+    /// Writes a code with a sub-parameter to the internal buffer
     ///
-    /// Returns self to allow for chaining
+    /// The two are separated by a colon, i.e. `4:3`
     #[inline]
-    pub fn chain_codes(&mut self, codes: &[u8]) -> &mut Self {
-        self.0.extend_from_slice(codes);
-        self
+    fn write_sub(&mut self, code: u8, sub: u8) {
+        self.write_param(Param::Sub(code, sub));
     }
     /// Writes buffered codes to the provided writer
     ///
+    /// Extended colors are written according to [`CapableWriter::color_encoding`]
+    ///
     /// # Errors
     ///
     /// Writing failed
-    pub fn write_to<W: CapableWriter>(&mut self, writer: &mut W) -> Result<(), W::Error> {
-        if self.0.is_empty() {
+    fn write_to<W: CapableWriter>(&mut self, writer: &mut W) -> Result<(), W::Error> {
+        if self.params().is_empty() {
             Ok(())
         } else {
-            writer.write("\x1b[")?;
-            self.codes_inner(writer)?;
-            writer.write("m")
+            write_params(self.params(), writer, "\x1b[", "m")
         }
     }
     /// Writes buffered codes to the writer
     ///
     /// Does not write the escape or end sequences
     ///
-    /// Performs IO operations with the inputted [`SGRWriter`]
-    ///
     /// # Errors
     ///
     /// Writing failed
-    pub fn write_partial<W: CapableWriter>(&mut self, writer: &mut W) -> Result<(), W::Error> {
-        if !self.0.is_empty() {
-            self.codes_inner(writer)?;
+    fn write_partial<W: CapableWriter>(&mut self, writer: &mut W) -> Result<(), W::Error> {
+        write_params(self.params(), writer, "", "")
+    }
+}
+/// Builds a SGR sequence without allocating
+///
+/// Holds up to [`InlineBuilder::CAPACITY`] parameters, more than any
+/// [`DiscreteSGR`] or [`SGRString`] writes besides its custom codes.
+///
+/// # Truncation
+///
+/// Parameters written once it is full are dropped without an error,
+/// leaving the sequence incomplete. Check [`InlineBuilder::fits`] before writing
+/// an [`SGRString`] with custom codes, or [`InlineBuilder::is_full`] after writing,
+/// to fall back to an allocating builder instead.
+///
+/// # Examples
+///
+///```rust
+///use easy_sgr::{CapableBuilder, Color::*, DiscreteSGR, InlineBuilder, Param};
+///
+///let mut builder = InlineBuilder::default();
+///RedFg.write(&mut builder);
+///builder.write_code(1);
+///
+///assert_eq!(builder.params(), [Param::Code(31), Param::Code(1)]);
+///```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InlineBuilder {
+    /// The parameters, of which only the first `len` are in use
+    params: [Param; Self::CAPACITY],
+    /// The amount of parameters in use
+    len: usize,
+}
+impl InlineBuilder {
+    /// The amount of parameters that fit in the builder
+    pub const CAPACITY: usize = 32;
+    /// The most parameters an [`SGRString`] writes, besides its custom codes
+    const STANDARD: usize = 24;
+    /// Whether an [`SGRString`] with the given amount of custom codes
+    /// always fits in the builder
+    #[must_use]
+    pub const fn fits(custom: usize) -> bool {
+        custom <= Self::CAPACITY - Self::STANDARD
+    }
+    /// Whether no more parameters can be written
+    #[must_use]
+    pub const fn is_full(&self) -> bool {
+        self.len == Self::CAPACITY
+    }
+}
+impl Default for InlineBuilder {
+    fn default() -> Self {
+        Self {
+            params: [Param::Code(0); Self::CAPACITY],
+            len: 0,
         }
-        Ok(())
     }
-    /// Writes the buffered codes into the inputted writer
-    fn codes_inner<W: CapableWriter>(&mut self, writer: &mut W) -> Result<(), W::Error> {
-        writer.write(&self.0[0].to_string())?;
-
-        for code in &self.0[1..] {
-            writer.write(";")?;
-            writer.write(&code.to_string())?;
+}
+impl CapableBuilder for InlineBuilder {
+    #[inline]
+    fn write_param(&mut self, param: Param) {
+        if !self.is_full() {
+            self.params[self.len] = param;
+            self.len += 1;
         }
-        Ok(())
+    }
+    #[inline]
+    fn params(&self) -> &[Param] {
+        &self.params[..self.len]
+    }
+    #[inline]
+    fn params_mut(&mut self) -> &mut [Param] {
+        &mut self.params[..self.len]
+    }
+    #[inline]
+    fn truncate(&mut self, len: usize) {
+        self.len = self.len.min(len);
+    }
+}
+/// Builds a SGR sequence
+#[derive(Debug, Default)]
+#[cfg(feature = "alloc")]
+pub struct SGRBuilder(pub Vec<Param>);
+#[cfg(feature = "alloc")]
+impl CapableBuilder for SGRBuilder {
+    #[inline]
+    fn write_param(&mut self, param: Param) {
+        self.0.push(param);
+    }
+    #[inline]
+    fn params(&self) -> &[Param] {
+        &self.0
+    }
+    #[inline]
+    fn params_mut(&mut self) -> &mut [Param] {
+        &mut self.0
+    }
+    #[inline]
+    fn truncate(&mut self, len: usize) {
+        self.0.truncate(len);
     }
 }
+#[cfg(feature = "alloc")]
+impl SGRBuilder {
+    /// Writes a code to the internal buffer
+    ///
+    /// Returns self to allow for chaining
+    #[inline]
+    pub fn chain_code(&mut self, code: u8) -> &mut Self {
+        self.write_code(code);
+        self
+    }
+    /// Writes codes to the internal buffer
+    ///
+    /// Returns self to allow for chaining
+    #[inline]
+    pub fn chain_codes(&mut self, codes: &[u8]) -> &mut Self {
+        self.write_codes(codes);
+        self
+    }
+    /// Writes a code with a sub-parameter to the internal buffer
+    ///
+    /// Returns self to allow for chaining
+    #[inline]
+    pub fn chain_sub(&mut self, code: u8, sub: u8) -> &mut Self {
+        self.write_sub(code, sub);
+        self
+    }
+    /// Writes a parameter to the internal buffer
+    ///
+    /// Returns self to allow for chaining
+    #[inline]
+    pub fn chain_param(&mut self, param: Param) -> &mut Self {
+        self.write_param(param);
+        self
+    }
+}
+/// Writes the given parameters into the inputted writer, between `start` & `end`
+///
+/// Codes are formatted into a [`CodeBuffer`], so that a sequence
+/// is written all at once unless it does not fit in the buffer
+fn write_params<W: CapableWriter>(
+    params: &[Param],
+    writer: &mut W,
+    start: &str,
+    end: &str,
+) -> Result<(), W::Error> {
+    let encoding = writer.color_encoding();
+    let mut buf: CodeBuffer = CodeBuffer::default();
+    buf.push(start);
+    for (i, param) in params.iter().enumerate() {
+        if !buf.fits(1 + Param::MAX_LEN + end.len()) {
+            buf.flush(writer)?;
+        }
+        if i != 0 {
+            buf.push(";");
+        }
+        param.encode_into(&mut buf, encoding);
+    }
+    buf.push(end);
+    buf.flush(writer)
+}
 
 /// Helps to make writing easier
 ///
@@ -268,23 +845,35 @@ impl SGRBuilder {
 /// [`SGRString`] and [`DiscreteSGR`] types
 pub trait EasyWrite {
     /// Writes a set of codes to the builder
-    fn sgr(&self, builder: &mut SGRBuilder);
+    fn sgr(&self, builder: &mut impl CapableBuilder);
+    /// Returns the amount of custom codes written, used to choose a builder
+    ///
+    /// By default `0`
+    #[inline]
+    fn custom_len(&self) -> usize {
+        0
+    }
 }
 
+#[cfg(feature = "alloc")]
 impl EasyWrite for SGRString {
     /// Writes a set of codes to the builder
     ///
     /// Uses [`SGRString::place_all`]
-    fn sgr(&self, builder: &mut SGRBuilder) {
+    fn sgr(&self, builder: &mut impl CapableBuilder) {
         self.place_all(builder);
     }
+    #[inline]
+    fn custom_len(&self) -> usize {
+        self.custom_places.len()
+    }
 }
 
 impl<D: DiscreteSGR> EasyWrite for D {
     /// Writes a set of codes to the builder
     ///
     /// Uses [`DiscreteSGR::write`]
-    fn sgr(&self, builder: &mut SGRBuilder) {
+    fn sgr(&self, builder: &mut impl CapableBuilder) {
         self.write(builder);
     }
 }
@@ -0,0 +1,70 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    fmt::Write,
+};
+
+use easy_sgr::{CleanKind, Color::*, EasySGR, SGRStyle, Style::*};
+
+struct Counter;
+thread_local! {
+    // counted per thread, as tests run in parallel
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+fn allocations() -> usize {
+    ALLOCATIONS.with(Cell::get)
+}
+
+unsafe impl GlobalAlloc for Counter {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+    }
+}
+#[global_allocator]
+static GLOBAL: Counter = Counter;
+
+/// A writer that does not allocate
+struct Buffer([u8; 64], usize);
+impl Write for Buffer {
+    fn write_str(&mut self, s: &str) -> std::fmt::Result {
+        let end = self.1 + s.len();
+        self.0
+            .get_mut(self.1..end)
+            .ok_or(std::fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.1 = end;
+        Ok(())
+    }
+}
+
+// kept in its own file, as the allocator is process wide
+#[test]
+fn discrete() {
+    let mut buf = Buffer([0; 64], 0);
+    let before = allocations();
+    write!(buf, "{Bold}{}{CurlyUnderline}{Reset}", RgbFg(255, 0, 0)).unwrap();
+    assert_eq!(before, allocations());
+    #[cfg(not(feature = "partial"))]
+    let expected = b"\x1b[1m\x1b[38;2;255;0;0m\x1b[4:3m\x1b[0m";
+    #[cfg(feature = "partial")]
+    let expected = b"138;2;255;0;04:30";
+    assert_eq!(&buf.0[..buf.1], expected);
+}
+
+#[test]
+fn painted() {
+    const STYLE: SGRStyle = SGRStyle::new()
+        .style(Italic)
+        .color(RedFg)
+        .clean(CleanKind::Reverse);
+    let value = 42;
+    let mut buf = Buffer([0; 64], 0);
+    let before = allocations();
+    write!(buf, "{}", STYLE.paint(&value)).unwrap();
+    assert_eq!(before, allocations());
+    assert_eq!(&buf.0[..buf.1], b"\x1b[31;3m42\x1b[39;23m");
+}
@@ -0,0 +1,119 @@
+use std::error::Error;
+
+use easy_sgr::{Color::*, ColorKind, ColorSupport, EasySGR, SGRString, SGRWriter, Style::*};
+
+fn detect(is_terminal: bool, vars: &[(&str, &str)]) -> ColorSupport {
+    ColorSupport::from_env(is_terminal, |key| {
+        vars.iter()
+            .find(|(name, _)| *name == key)
+            .map(|(_, value)| (*value).to_string())
+    })
+}
+
+#[test]
+fn from_env() {
+    use ColorSupport::*;
+    for (correct, is_terminal, vars) in [
+        (Basic16, true, &[][..]),
+        (None, false, &[]),
+        (None, false, &[("TERM", "xterm-256color")]),
+        (Basic16, true, &[("TERM", "xterm")]),
+        (Ansi256, true, &[("TERM", "xterm-256color")]),
+        (TrueColor, true, &[("TERM", "xterm-direct")]),
+        (
+            TrueColor,
+            true,
+            &[("TERM", "xterm"), ("COLORTERM", "truecolor")],
+        ),
+        (TrueColor, true, &[("COLORTERM", "24bit")]),
+        (None, true, &[("TERM", "dumb")]),
+        (None, true, &[("NO_COLOR", "1"), ("COLORTERM", "24bit")]),
+        (Basic16, true, &[("NO_COLOR", "")]),
+        (None, true, &[("NO_COLOR", "1"), ("CLICOLOR_FORCE", "1")]),
+        (Basic16, false, &[("CLICOLOR_FORCE", "1")]),
+        (Basic16, false, &[("CLICOLOR_FORCE", "1"), ("TERM", "dumb")]),
+        (None, false, &[("CLICOLOR_FORCE", "0")]),
+        (
+            Ansi256,
+            false,
+            &[("CLICOLOR_FORCE", "1"), ("TERM", "screen-256color")],
+        ),
+    ] {
+        assert_eq!(correct, detect(is_terminal, vars), "{is_terminal} {vars:?}");
+    }
+    assert!(ColorSupport::None < ColorSupport::Basic16);
+    assert!(ColorSupport::Ansi256 < ColorSupport::TrueColor);
+}
+
+fn written(support: ColorSupport, sgr: &SGRString) -> Result<String, Box<dyn Error>> {
+    let mut writer = SGRWriter::from(String::new());
+    writer.color_support = support;
+    writer.place_sgr(sgr)?;
+    writer.write_inner("test")?;
+    writer.clean_sgr(sgr)?;
+    Ok(writer.internal())
+}
+
+#[test]
+fn downgrade() -> Result<(), Box<dyn Error>> {
+    use ColorSupport::*;
+    for (support, fg, bg, correct) in [
+        (
+            TrueColor,
+            RgbFg(255, 0, 0),
+            RgbBg(128, 128, 128),
+            "\x1b[38;2;255;0;0;48;2;128;128;128;1m",
+        ),
+        (
+            Ansi256,
+            RgbFg(255, 0, 0),
+            RgbBg(128, 128, 128),
+            "\x1b[38;5;196;48;5;244;1m",
+        ),
+        (Ansi256, ByteFg(208), RedBg, "\x1b[38;5;208;41;1m"),
+        (Basic16, RgbFg(255, 0, 0), RgbBg(0, 0, 0), "\x1b[91;40;1m"),
+        (Basic16, ByteFg(196), ByteBg(4), "\x1b[91;44;1m"),
+        (Basic16, ByteFg(12), ByteBg(244), "\x1b[94;100;1m"),
+        (None, RgbFg(255, 0, 0), ByteBg(4), "\x1b[1m"),
+        (None, RedFg, DefaultBg, "\x1b[1m"),
+    ] {
+        let sgr = fg.color(bg).style(Bold);
+        assert_eq!(format!("{correct}test"), written(support, &sgr)?);
+    }
+    let sgr = SGRString {
+        foreground: ColorKind::Rgb(1, 2, 3),
+        custom_places: vec![38, 5],
+        ..Default::default()
+    }
+    .clean(easy_sgr::CleanKind::Reverse);
+    assert_eq!("\x1b[38;5;16;38;5mtest\x1b[39m", written(Ansi256, &sgr)?);
+    assert_eq!("test", written(None, &sgr)?);
+
+    // custom extended colors are removed along with the codes they are made of
+    for (codes, correct) in [
+        (vec![38, 5, 208, 1], "\x1b[1mtest"),
+        (vec![3, 48, 2, 1, 2, 3, 5, 1], "\x1b[3;5;1mtest"),
+        (vec![58, 5], "test"),
+        (vec![38, 9, 1], "\x1b[9;1mtest"),
+    ] {
+        let sgr = codes
+            .into_iter()
+            .fold(SGRString::default(), EasySGR::custom);
+        assert_eq!(correct, written(None, &sgr)?);
+    }
+    let sgr = SGRString::from_codes("38;5;4:3;1")?;
+    assert_eq!("\x1b[1;4:3mtest", written(None, &sgr)?);
+
+    let mut writer = SGRWriter::from(String::new());
+    writer.color_support = None;
+    writer.inline_sgr(&RedFg)?;
+    writer.partial_sgr(&RgbBg(1, 2, 3))?;
+    writer.sgr(&Italic)?;
+    assert_eq!("\x1b[3m", writer.internal());
+
+    let sgr = RgbUl(255, 0, 0).style(CurlyUnderline);
+    assert_eq!("\x1b[58;5;196;4:3mtest", written(Ansi256, &sgr)?);
+    assert_eq!("\x1b[58;5;9;4:3mtest", written(Basic16, &sgr)?);
+    assert_eq!("\x1b[4:3mtest", written(None, &sgr)?);
+    Ok(())
+}
@@ -0,0 +1,80 @@
+use easy_sgr::{
+    byte_to_rgb, parse_hex, rgb_to_basic, rgb_to_byte, rgb_to_standard, to_hex, Color, Color::*,
+    ColorKind, ParseHexError,
+};
+
+#[test]
+fn palette() {
+    for n in 16..=255 {
+        let (r, g, b) = byte_to_rgb(n);
+        assert_eq!(rgb_to_byte(r, g, b), n, "{n}");
+    }
+    assert_eq!(rgb_to_byte(0, 0, 0), 16);
+    assert_eq!(rgb_to_byte(250, 250, 250), 231);
+    assert_eq!(rgb_to_byte(100, 100, 100), 241);
+
+    assert_eq!(rgb_to_basic(0, 0, 230), 4);
+    assert_eq!(rgb_to_basic(100, 100, 255), 12);
+    assert_eq!(rgb_to_standard(100, 100, 255), 4);
+    assert_eq!(rgb_to_standard(255, 255, 255), 7);
+}
+
+#[test]
+fn hex() {
+    assert_eq!(parse_hex("#1a2B3c"), Ok((26, 43, 60)));
+    assert_eq!(parse_hex("#abc"), Ok((170, 187, 204)));
+    assert_eq!(parse_hex("000000"), Ok((0, 0, 0)));
+    assert_eq!(parse_hex("#ff"), Err(ParseHexError::Len(2)));
+    assert_eq!(parse_hex(""), Err(ParseHexError::Len(0)));
+    assert_eq!(parse_hex("#ggg"), Err(ParseHexError::Digit('g')));
+    assert_eq!(parse_hex("##fff"), Err(ParseHexError::Digit('#')));
+
+    assert_eq!(to_hex(26, 43, 60), "#1a2b3c");
+    assert_eq!(
+        ColorKind::from_hex("#abc"),
+        Ok(ColorKind::Rgb(170, 187, 204))
+    );
+    assert_eq!(ColorKind::Byte(196).to_hex().as_deref(), Some("#ff0000"));
+    assert_eq!(ColorKind::Default.to_hex(), None);
+    assert_eq!(Color::from_hex_fg("#010203"), Ok(RgbFg(1, 2, 3)));
+    assert_eq!(Color::from_hex_bg("#010203"), Ok(RgbBg(1, 2, 3)));
+    assert_eq!(Color::from_hex_ul("#0a0b0c"), Ok(RgbUl(10, 11, 12)));
+    assert!(Color::from_hex_ul("#0a0b0").is_err());
+    assert_eq!(BlueBg.to_hex().as_deref(), Some("#0000ee"));
+    assert_eq!(DefaultFg.to_hex(), None);
+}
+
+#[test]
+fn color_kind() {
+    use ColorKind::*;
+    assert_eq!(Red.rgb(), Some((205, 0, 0)));
+    assert_eq!(Red.to_rgb(), Rgb(205, 0, 0));
+    assert_eq!(Byte(232).to_rgb(), Rgb(8, 8, 8));
+    assert_eq!(None.to_rgb(), None);
+    assert_eq!(Rgb(255, 0, 0).to_byte(), Byte(196));
+    assert_eq!(Red.to_byte(), Red);
+    assert_eq!(Rgb(10, 200, 20).to_standard(), Green);
+    assert_eq!(Byte(1).to_standard(), Red);
+    assert_eq!(Default.to_standard(), Default);
+    assert_eq!(BrightRed.rgb(), Some((255, 0, 0)));
+    assert_eq!(BrightGreen.to_standard(), Green);
+    assert_eq!(Rgb(100, 100, 255).to_basic(), BrightBlue);
+    assert_eq!(Byte(9).to_basic(), BrightRed);
+    assert_eq!(Byte(160).to_basic(), Red);
+    assert_eq!(White.to_basic(), White);
+}
+
+#[test]
+fn color() {
+    assert_eq!(RedFg.rgb(), Some((205, 0, 0)));
+    assert_eq!(RedBg.to_rgb(), RgbBg(205, 0, 0));
+    assert_eq!(ByteFg(16).to_rgb(), RgbFg(0, 0, 0));
+    assert_eq!(RgbFg(255, 0, 0).to_byte(), ByteFg(196));
+    assert_eq!(RgbBg(255, 0, 0).to_byte(), ByteBg(196));
+    assert_eq!(ByteBg(46).to_standard(), GreenBg);
+    assert_eq!(RgbFg(250, 250, 250).to_standard(), WhiteFg);
+    assert_eq!(DefaultBg.to_byte(), DefaultBg);
+    assert_eq!(ByteFg(12).to_basic(), BrightBlueFg);
+    assert_eq!(BrightCyanBg.to_rgb(), RgbBg(0, 255, 255));
+    assert_eq!(BrightCyanBg.to_standard(), CyanBg);
+}
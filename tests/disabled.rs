@@ -0,0 +1,16 @@
+use easy_sgr::{is_sgr_enabled, set_sgr_enabled, Color::*, EasySGR, Seq::*, Style::*};
+
+// kept in its own file, as the switch is process wide
+#[test]
+fn global_switch() {
+    assert!(is_sgr_enabled());
+    set_sgr_enabled(false);
+    assert!(!is_sgr_enabled());
+
+    assert_eq!(format!("{Esc}{Bold}{RedFg}{End}"), "");
+    assert_eq!("test".style(Bold).color(RedFg).to_string(), "test");
+    assert_eq!(format!("{:>6}", "test".style(Bold)), "  test");
+
+    set_sgr_enabled(true);
+    assert_eq!("test".style(Bold).to_string(), "\x1b[1mtest");
+}
@@ -29,6 +29,26 @@ mod normal {
             ("\x1b[27m", NotInverse),
             ("\x1b[28m", NotHidden),
             ("\x1b[29m", NotStrikethrough),
+            ("\x1b[4:3m", CurlyUnderline),
+            ("\x1b[21m", DoublyUnderlined),
+            ("\x1b[26m", ProportionalSpacing),
+            ("\x1b[51m", Framed),
+            ("\x1b[52m", Encircled),
+            ("\x1b[53m", Overline),
+            ("\x1b[10m", PrimaryFont),
+            ("\x1b[20m", Fraktur),
+            ("\x1b[60m", IdeogramUnderline),
+            ("\x1b[64m", IdeogramStress),
+            ("\x1b[73m", Superscript),
+            ("\x1b[74m", Subscript),
+            ("\x1b[50m", NotProportionalSpacing),
+            ("\x1b[54m", NotFramed),
+            ("\x1b[54m", NotEncircled),
+            ("\x1b[55m", NotOverline),
+            ("\x1b[65m", NotIdeogram),
+            ("\x1b[75m", NotSuperscript),
+            ("\x1b[75m", NotSubscript),
+            ("\x1b[13m", AlternateFont(3)),
         ] {
             assert_eq!(correct, format!("{style}"))
         }
@@ -44,6 +64,14 @@ mod normal {
             ("\x1b[35m", MagentaFg),
             ("\x1b[36m", CyanFg),
             ("\x1b[37m", WhiteFg),
+            ("\x1b[90m", BrightBlackFg),
+            ("\x1b[91m", BrightRedFg),
+            ("\x1b[92m", BrightGreenFg),
+            ("\x1b[93m", BrightYellowFg),
+            ("\x1b[94m", BrightBlueFg),
+            ("\x1b[95m", BrightMagentaFg),
+            ("\x1b[96m", BrightCyanFg),
+            ("\x1b[97m", BrightWhiteFg),
             ("\x1b[39m", DefaultFg),
             ("\x1b[40m", BlackBg),
             ("\x1b[41m", RedBg),
@@ -53,7 +81,16 @@ mod normal {
             ("\x1b[45m", MagentaBg),
             ("\x1b[46m", CyanBg),
             ("\x1b[47m", WhiteBg),
+            ("\x1b[100m", BrightBlackBg),
+            ("\x1b[101m", BrightRedBg),
+            ("\x1b[102m", BrightGreenBg),
+            ("\x1b[103m", BrightYellowBg),
+            ("\x1b[104m", BrightBlueBg),
+            ("\x1b[105m", BrightMagentaBg),
+            ("\x1b[106m", BrightCyanBg),
+            ("\x1b[107m", BrightWhiteBg),
             ("\x1b[49m", DefaultBg),
+            ("\x1b[59m", DefaultUl),
         ] {
             assert_eq!(correct, format!("{color}"))
         }
@@ -63,6 +100,7 @@ mod normal {
         for i in (0u8..255).step_by(17) {
             assert_eq!(format!("\x1b[38;5;{i}m"), format!("{}", ByteFg(i)));
             assert_eq!(format!("\x1b[48;5;{i}m"), format!("{}", ByteBg(i)));
+            assert_eq!(format!("\x1b[58;5;{i}m"), format!("{}", ByteUl(i)));
         }
     }
 
@@ -115,6 +153,26 @@ mod from_str {
             ("NotInverse", NotInverse),
             ("NotHidden", NotHidden),
             ("NotStrikethrough", NotStrikethrough),
+            ("CurlyUnderline", CurlyUnderline),
+            ("DoublyUnderlined", DoublyUnderlined),
+            ("ProportionalSpacing", ProportionalSpacing),
+            ("Framed", Framed),
+            ("Encircled", Encircled),
+            ("Overline", Overline),
+            ("PrimaryFont", PrimaryFont),
+            ("Fraktur", Fraktur),
+            ("IdeogramUnderline", IdeogramUnderline),
+            ("IdeogramStress", IdeogramStress),
+            ("Superscript", Superscript),
+            ("Subscript", Subscript),
+            ("NotProportionalSpacing", NotProportionalSpacing),
+            ("NotFramed", NotFramed),
+            ("NotEncircled", NotEncircled),
+            ("NotOverline", NotOverline),
+            ("NotIdeogram", NotIdeogram),
+            ("NotSuperscript", NotSuperscript),
+            ("NotSubscript", NotSubscript),
+            ("AlternateFont(3)", AlternateFont(3)),
         ] {
             assert_eq!(Ok(style), src.parse())
         }
@@ -130,6 +188,14 @@ mod from_str {
             ("MagentaFg", MagentaFg),
             ("CyanFg", CyanFg),
             ("WhiteFg", WhiteFg),
+            ("BrightBlackFg", BrightBlackFg),
+            ("BrightRedFg", BrightRedFg),
+            ("BrightGreenFg", BrightGreenFg),
+            ("BrightYellowFg", BrightYellowFg),
+            ("BrightBlueFg", BrightBlueFg),
+            ("BrightMagentaFg", BrightMagentaFg),
+            ("BrightCyanFg", BrightCyanFg),
+            ("BrightWhiteFg", BrightWhiteFg),
             ("DefaultFg", DefaultFg),
             ("BlackBg", BlackBg),
             ("RedBg", RedBg),
@@ -139,7 +205,16 @@ mod from_str {
             ("MagentaBg", MagentaBg),
             ("CyanBg", CyanBg),
             ("WhiteBg", WhiteBg),
+            ("BrightBlackBg", BrightBlackBg),
+            ("BrightRedBg", BrightRedBg),
+            ("BrightGreenBg", BrightGreenBg),
+            ("BrightYellowBg", BrightYellowBg),
+            ("BrightBlueBg", BrightBlueBg),
+            ("BrightMagentaBg", BrightMagentaBg),
+            ("BrightCyanBg", BrightCyanBg),
+            ("BrightWhiteBg", BrightWhiteBg),
             ("DefaultBg", DefaultBg),
+            ("DefaultUl", DefaultUl),
         ] {
             assert_eq!(Ok(color), src.parse())
         }
@@ -149,6 +224,7 @@ mod from_str {
         for i in (0u8..255).step_by(17) {
             assert_eq!(Ok(ByteFg(i)), format!("ByteFg({i})").parse());
             assert_eq!(Ok(ByteBg(i)), format!("ByteBg({i})").parse());
+            assert_eq!(Ok(ByteUl(i)), format!("ByteUl({i})").parse());
         }
     }
     #[test]
@@ -158,6 +234,7 @@ mod from_str {
                 for k in (0u8..255).step_by(17) {
                     assert_eq!(Ok(RgbFg(i, j, k)), format!("RgbFg({i},{j},{k})").parse());
                     assert_eq!(Ok(RgbBg(i, j, k)), format!("RgbBg({i},{j},{k})").parse());
+                    assert_eq!(Ok(RgbUl(i, j, k)), format!("RgbUl({i},{j},{k})").parse());
                 }
             }
         }
@@ -187,6 +264,25 @@ mod partial {
             ("27", NotInverse),
             ("28", NotHidden),
             ("29", NotStrikethrough),
+            ("4:3", CurlyUnderline),
+            ("21", DoublyUnderlined),
+            ("26", ProportionalSpacing),
+            ("51", Framed),
+            ("52", Encircled),
+            ("53", Overline),
+            ("10", PrimaryFont),
+            ("20", Fraktur),
+            ("60", IdeogramUnderline),
+            ("64", IdeogramStress),
+            ("73", Superscript),
+            ("74", Subscript),
+            ("50", NotProportionalSpacing),
+            ("54", NotFramed),
+            ("54", NotEncircled),
+            ("55", NotOverline),
+            ("65", NotIdeogram),
+            ("75", NotSuperscript),
+            ("75", NotSubscript),
         ] {
             assert_eq!(correct, format!("{style}"))
         }
@@ -202,6 +298,14 @@ mod partial {
             ("35", MagentaFg),
             ("36", CyanFg),
             ("37", WhiteFg),
+            ("90", BrightBlackFg),
+            ("91", BrightRedFg),
+            ("92", BrightGreenFg),
+            ("93", BrightYellowFg),
+            ("94", BrightBlueFg),
+            ("95", BrightMagentaFg),
+            ("96", BrightCyanFg),
+            ("97", BrightWhiteFg),
             ("39", DefaultFg),
             ("40", BlackBg),
             ("41", RedBg),
@@ -211,7 +315,16 @@ mod partial {
             ("45", MagentaBg),
             ("46", CyanBg),
             ("47", WhiteBg),
+            ("100", BrightBlackBg),
+            ("101", BrightRedBg),
+            ("102", BrightGreenBg),
+            ("103", BrightYellowBg),
+            ("104", BrightBlueBg),
+            ("105", BrightMagentaBg),
+            ("106", BrightCyanBg),
+            ("107", BrightWhiteBg),
             ("49", DefaultBg),
+            ("59", DefaultUl),
         ] {
             assert_eq!(correct, format!("{color}"))
         }
@@ -221,6 +334,7 @@ mod partial {
         for i in (0u8..255).step_by(17) {
             assert_eq!(format!("38;5;{i}"), format!("{}", ByteFg(i)));
             assert_eq!(format!("48;5;{i}"), format!("{}", ByteBg(i)));
+            assert_eq!(format!("58;5;{i}"), format!("{}", ByteUl(i)));
         }
     }
     #[test]
@@ -1,6 +1,9 @@
 use std::default::Default;
 
-use easy_sgr::{CleanKind, Color::*, ColorKind, EasySGR, SGRString, Style::*, StyleKind};
+use easy_sgr::{
+    CleanKind, Color::*, ColorKind, EasySGR, FontKind, IdeogramKind, PadKind, Painted, SGRString,
+    SGRWriter, Style::*, StyleKind, UnderlineKind,
+};
 
 #[test]
 fn general() {
@@ -36,44 +39,68 @@ fn general() {
 #[test]
 fn fully_loaded() {
     assert_eq!(
-        "\x1b[0;31;41;1;2;3;4;5;7;8;9;100mtest\x1b[39;49;22;22;23;24;25;27;28;29;100m",
+        "\x1b[0;31;41;58;5;1;1;2;3;4;5;7;8;9;21;26;51;52;53;73;74;4:3;13;64;100mtest\x1b[39;49;59;22;22;23;24;25;27;28;29;24;50;54;54;55;75;75;24;10;65;100m",
         SGRString {
             text: "test".to_string(),
             clean: CleanKind::Reverse,
+            pad: PadKind::Inside,
             custom_places: vec![100],
             custom_cleans: vec![100],
             foreground: ColorKind::Red,
             background: ColorKind::Red,
+            underline_color: ColorKind::Red,
             reset: true,
             bold: StyleKind::Place,
             dim: StyleKind::Place,
             italic: StyleKind::Place,
             underline: StyleKind::Place,
+            underline_style: UnderlineKind::Curly,
             blinking: StyleKind::Place,
             inverse: StyleKind::Place,
             hidden: StyleKind::Place,
-            strikethrough: StyleKind::Place
+            strikethrough: StyleKind::Place,
+            doubly_underlined: StyleKind::Place,
+            proportional_spacing: StyleKind::Place,
+            framed: StyleKind::Place,
+            encircled: StyleKind::Place,
+            overline: StyleKind::Place,
+            superscript: StyleKind::Place,
+            subscript: StyleKind::Place,
+            font: FontKind::Alternate(3),
+            ideogram: IdeogramKind::Stress,
         }
         .to_string()
     );
     assert_eq!(
-        "\x1b[0;31;41;22;22;23;24;25;27;28;29;100mtest\x1b[39;49;1;2;3;4;5;7;8;9;100m",
+        "\x1b[0;31;41;22;22;23;24;25;27;28;29;24;50;54;54;55;75;75;100mtest\x1b[39;49;1;2;3;4;5;7;8;9;21;26;51;52;53;73;74;100m",
         SGRString {
             text: "test".to_string(),
             clean: CleanKind::Reverse,
+            pad: PadKind::Inside,
             custom_places: vec![100],
             custom_cleans: vec![100],
             foreground: ColorKind::Red,
             background: ColorKind::Red,
+            underline_color: ColorKind::None,
             reset: true,
             bold: StyleKind::Clean,
             dim: StyleKind::Clean,
             italic: StyleKind::Clean,
             underline: StyleKind::Clean,
+            underline_style: UnderlineKind::None,
             blinking: StyleKind::Clean,
             inverse: StyleKind::Clean,
             hidden: StyleKind::Clean,
-            strikethrough: StyleKind::Clean
+            strikethrough: StyleKind::Clean,
+            doubly_underlined: StyleKind::Clean,
+            proportional_spacing: StyleKind::Clean,
+            framed: StyleKind::Clean,
+            encircled: StyleKind::Clean,
+            overline: StyleKind::Clean,
+            superscript: StyleKind::Clean,
+            subscript: StyleKind::Clean,
+            font: FontKind::None,
+            ideogram: IdeogramKind::None,
         }
         .to_string()
     );
@@ -91,6 +118,14 @@ fn colors() {
         ("\x1b[35;45m", ColorKind::Magenta),
         ("\x1b[36;46m", ColorKind::Cyan),
         ("\x1b[37;47m", ColorKind::White),
+        ("\x1b[90;100m", ColorKind::BrightBlack),
+        ("\x1b[91;101m", ColorKind::BrightRed),
+        ("\x1b[92;102m", ColorKind::BrightGreen),
+        ("\x1b[93;103m", ColorKind::BrightYellow),
+        ("\x1b[94;104m", ColorKind::BrightBlue),
+        ("\x1b[95;105m", ColorKind::BrightMagenta),
+        ("\x1b[96;106m", ColorKind::BrightCyan),
+        ("\x1b[97;107m", ColorKind::BrightWhite),
         ("\x1b[38;5;208;48;5;208m", ColorKind::Byte(208)),
         (
             "\x1b[38;2;208;208;208;48;2;208;208;208m",
@@ -142,6 +177,34 @@ fn easy_sgr_style() {
         ("\x1b[27m", NotInverse),
         ("\x1b[28m", NotHidden),
         ("\x1b[29m", NotStrikethrough),
+        ("\x1b[4:2m", DoubleUnderline),
+        ("\x1b[4:3m", CurlyUnderline),
+        ("\x1b[4:4m", DottedUnderline),
+        ("\x1b[4:5m", DashedUnderline),
+        ("\x1b[21m", DoublyUnderlined),
+        ("\x1b[26m", ProportionalSpacing),
+        ("\x1b[51m", Framed),
+        ("\x1b[52m", Encircled),
+        ("\x1b[53m", Overline),
+        ("\x1b[10m", PrimaryFont),
+        ("\x1b[11m", AlternateFont(1)),
+        ("\x1b[19m", AlternateFont(9)),
+        ("\x1b[19m", AlternateFont(12)),
+        ("\x1b[20m", Fraktur),
+        ("\x1b[60m", IdeogramUnderline),
+        ("\x1b[61m", IdeogramDoubleUnderline),
+        ("\x1b[62m", IdeogramOverline),
+        ("\x1b[63m", IdeogramDoubleOverline),
+        ("\x1b[64m", IdeogramStress),
+        ("\x1b[73m", Superscript),
+        ("\x1b[74m", Subscript),
+        ("\x1b[50m", NotProportionalSpacing),
+        ("\x1b[54m", NotFramed),
+        ("\x1b[54m", NotEncircled),
+        ("\x1b[55m", NotOverline),
+        ("\x1b[65m", NotIdeogram),
+        ("\x1b[75m", NotSuperscript),
+        ("\x1b[75m", NotSubscript),
     ] {
         assert_eq!(correct, "".style(style).to_string());
     }
@@ -158,6 +221,14 @@ fn easy_sgr_color() {
         ("\x1b[35m", MagentaFg),
         ("\x1b[36m", CyanFg),
         ("\x1b[37m", WhiteFg),
+        ("\x1b[90m", BrightBlackFg),
+        ("\x1b[91m", BrightRedFg),
+        ("\x1b[92m", BrightGreenFg),
+        ("\x1b[93m", BrightYellowFg),
+        ("\x1b[94m", BrightBlueFg),
+        ("\x1b[95m", BrightMagentaFg),
+        ("\x1b[96m", BrightCyanFg),
+        ("\x1b[97m", BrightWhiteFg),
         ("\x1b[38;5;208m", ByteFg(208)),
         ("\x1b[38;2;208;208;208m", RgbFg(208, 208, 208)),
         ("\x1b[39m", DefaultFg),
@@ -169,10 +240,122 @@ fn easy_sgr_color() {
         ("\x1b[45m", MagentaBg),
         ("\x1b[46m", CyanBg),
         ("\x1b[47m", WhiteBg),
+        ("\x1b[100m", BrightBlackBg),
+        ("\x1b[101m", BrightRedBg),
+        ("\x1b[102m", BrightGreenBg),
+        ("\x1b[103m", BrightYellowBg),
+        ("\x1b[104m", BrightBlueBg),
+        ("\x1b[105m", BrightMagentaBg),
+        ("\x1b[106m", BrightCyanBg),
+        ("\x1b[107m", BrightWhiteBg),
         ("\x1b[48;5;208m", ByteBg(208)),
         ("\x1b[48;2;208;208;208m", RgbBg(208, 208, 208)),
         ("\x1b[49m", DefaultBg),
+        ("\x1b[58;5;208m", ByteUl(208)),
+        ("\x1b[58;2;208;208;208m", RgbUl(208, 208, 208)),
+        ("\x1b[59m", DefaultUl),
     ] {
         assert_eq!(correct, "".color(color).to_string())
     }
 }
+
+#[test]
+fn formatting() {
+    let sgr = "test".color(RedBg).clean(CleanKind::Reset);
+    for (correct, formatted) in [
+        ("\x1b[41mtest\x1b[0m", format!("{sgr:2}")),
+        ("\x1b[41mtest  \x1b[0m", format!("{sgr:6}")),
+        ("\x1b[41mtest  \x1b[0m", format!("{sgr:<6}")),
+        ("\x1b[41m  test\x1b[0m", format!("{sgr:>6}")),
+        ("\x1b[41m-test--\x1b[0m", format!("{sgr:-^7}")),
+        ("\x1b[41mte\x1b[0m", format!("{sgr:.2}")),
+        ("\x1b[41mte    \x1b[0m", format!("{sgr:6.2}")),
+    ] {
+        assert_eq!(correct, formatted);
+    }
+    let sgr = sgr.pad(PadKind::Outside);
+    assert_eq!("\x1b[41mtest\x1b[0m  ", format!("{sgr:6}"));
+    assert_eq!("☀\x1b[41mtest\x1b[0m☀☀", format!("{sgr:☀^7}"));
+
+    let sgr = "日本\x1b[1m語".to_sgr();
+    assert_eq!("日本\x1b[1m語 ", format!("{sgr:7}"));
+    assert_eq!("日\x1b[1m ", format!("{sgr:3.3}"));
+}
+
+#[test]
+fn underline() {
+    let sgr = "test"
+        .style(CurlyUnderline)
+        .color(ByteUl(208))
+        .clean(CleanKind::Reverse);
+    assert_eq!("\x1b[58;5;208;4:3mtest\x1b[59;24m", sgr.to_string());
+
+    let sgr = SGRString {
+        underline_color: ColorKind::BrightRed,
+        underline_style: UnderlineKind::Single,
+        ..Default::default()
+    };
+    assert_eq!("\x1b[58;5;9;4:1m", sgr.to_string());
+}
+
+#[test]
+fn clean_within() {
+    let clean = |inner: SGRString, outer: SGRString| {
+        let mut w = SGRWriter::from(String::new());
+        w.clean_sgr_within(&inner.clean(CleanKind::Reverse), &outer)
+            .unwrap();
+        w.internal()
+    };
+    assert_eq!("\x1b[22;2m", clean(Bold.into(), Dim.into()));
+    assert_eq!("\x1b[22m", clean(Bold.into(), Italic.into()));
+    assert_eq!("", clean(Bold.into(), Bold.style(Dim)));
+    assert_eq!("\x1b[1m", clean(NotBold.into(), Bold.into()));
+    assert_eq!("", clean(NotBold.into(), Dim.into()));
+    assert_eq!("\x1b[54;52m", clean(Framed.into(), Encircled.into()));
+    assert_eq!("\x1b[75;73m", clean(Subscript.into(), Superscript.into()));
+    assert_eq!("\x1b[24;4m", clean(CurlyUnderline.into(), Underline.into()));
+    assert_eq!(
+        "\x1b[4:3m",
+        clean(DashedUnderline.into(), CurlyUnderline.into())
+    );
+    assert_eq!("\x1b[10m", clean(Fraktur.into(), Italic.into()));
+    assert_eq!(
+        "\x1b[11m",
+        clean(PrimaryFont.into(), AlternateFont(1).into())
+    );
+    assert_eq!("\x1b[49;31m", clean(BlueFg.color(RedBg), RedFg.into()));
+    assert_eq!("", clean(RedFg.into(), RedFg.into()));
+
+    let mut w = SGRWriter::from(String::new());
+    w.clean_sgr_within(&Bold.clean(CleanKind::Reset), &RedFg.style(Dim))
+        .unwrap();
+    assert_eq!("\x1b[0;31;2m", w.internal());
+}
+
+#[test]
+fn painted() {
+    let style = Bold.color(RedFg).clean(CleanKind::Reverse);
+    assert_eq!(
+        style.clone().text("text").to_string(),
+        style.clone().paint("text").to_string()
+    );
+    assert_eq!(
+        "\x1b[31;1m42\x1b[39;22m",
+        Painted::new(&style, 42).to_string()
+    );
+    assert_eq!(
+        "\x1b[31;1m   4.20\x1b[39;22m",
+        format!("{:>7.2}", Painted::new(&style, 4.2))
+    );
+    assert_eq!(
+        "\x1b[3mborrowed",
+        Italic
+            .paint(std::borrow::Cow::Borrowed("borrowed"))
+            .to_string()
+    );
+    // the text of the style is not written
+    assert_eq!(
+        "\x1b[1mpainted",
+        Bold.text("text").paint("painted").to_string()
+    );
+}
@@ -0,0 +1,150 @@
+use std::error::Error;
+
+use easy_sgr::{
+    CleanKind, Color::*, ColorKind, EasySGR, ParseGitColorError, ParseKeywordsError, SGRString,
+    Style::*, StyleKind,
+};
+
+fn git(s: &str) -> SGRString {
+    SGRString::from_git_color(s).unwrap()
+}
+
+#[test]
+fn git_colors() {
+    assert_eq!(git("red"), RedFg.into());
+    assert_eq!(git("red blue"), RedFg.color(BlueBg));
+    assert_eq!(git("brightgreen 0"), BrightGreenFg.color(BlackBg));
+    assert_eq!(git("7 8 "), WhiteFg.color(ByteBg(8)));
+    assert_eq!(git("208"), ByteFg(208).into());
+    assert_eq!(git("#ff00aa"), RgbFg(255, 0, 170).into());
+    assert_eq!(git("#f0a default"), RgbFg(255, 0, 170).color(DefaultBg));
+    assert_eq!(git("normal Yellow"), YellowBg.into());
+    assert_eq!(git("-1 -1"), SGRString::default());
+    assert_eq!(git(""), SGRString::default());
+}
+
+#[test]
+fn git_attributes() {
+    assert_eq!(git("red bold ul"), RedFg.style(Bold).style(Underline));
+    assert_eq!(
+        git("dim italic blink reverse strike underline"),
+        Dim.style(Italic)
+            .style(Blinking)
+            .style(Inverse)
+            .style(Strikethrough)
+            .style(Underline)
+    );
+    assert_eq!(git("nobold no-ul"), NotBold.style(NotUnderline));
+    assert_eq!(git("reset BOLD"), Reset.style(Bold));
+
+    let sgr = git("#ff00aa black italic nobold");
+    assert_eq!(sgr.foreground, ColorKind::Rgb(255, 0, 170));
+    assert_eq!(sgr.background, ColorKind::Black);
+    assert_eq!(sgr.italic, StyleKind::Place);
+    assert_eq!(sgr.bold, StyleKind::Clean);
+    assert_eq!(
+        "\x1b[38;2;255;0;170;40;22;3mold\x1b[39;49;1;23m",
+        sgr.text("old").clean(CleanKind::Reverse).to_string()
+    );
+}
+
+#[test]
+fn git_errors() {
+    assert_eq!(
+        SGRString::from_git_color("red blue green"),
+        Err(ParseGitColorError::Colors("green".into()))
+    );
+    for word in ["256", "-2", "#ff00", "purple", "nored", "noreset", "bright"] {
+        assert_eq!(
+            SGRString::from_git_color(word),
+            Err(ParseGitColorError::Word(word.into()))
+        );
+    }
+    assert_eq!(
+        SGRString::from_git_color("bold wavy")
+            .unwrap_err()
+            .to_string(),
+        "Invalid git color word: wavy"
+    );
+}
+
+fn keywords(s: &str) -> SGRString {
+    SGRString::from_keywords(s).unwrap()
+}
+
+#[test]
+fn keyword_styles() {
+    assert_eq!(keywords(""), Reset.into());
+    assert_eq!(keywords("reset bold"), Reset.style(Bold));
+    assert_eq!(keywords("italic underline"), Italic.style(Underline));
+    assert_eq!(keywords("!italic !hide"), NotItalic.style(NotHidden));
+    assert_eq!(keywords("bold !dim"), Bold.style(NotDim));
+    assert_eq!(keywords("!bold").to_string(), "\x1b[22m");
+    assert_eq!(keywords("curly-underline"), CurlyUnderline.into());
+    assert_eq!(
+        "\x1b[1;3mtext\x1b[22;23m",
+        keywords(" bold\titalic ")
+            .text("text")
+            .clean(CleanKind::Reverse)
+            .to_string()
+    );
+}
+
+#[test]
+#[cfg(feature = "macros")]
+fn keyword_macros() {
+    use easy_sgr::sgr;
+
+    for (words, result) in [
+        ("", sgr!("{[]}")),
+        ("!bold", sgr!("{[!bold]}")),
+        ("!dim", sgr!("{[!dim]}")),
+        ("bold !dim italic", sgr!("{[bold !dim italic]}")),
+        ("reset red on-blue", sgr!("{[reset red on-blue]}")),
+        ("#0f on-15,115,215", sgr!("{[#0f on-15,115,215]}")),
+    ] {
+        assert_eq!(keywords(words).to_string(), result);
+    }
+}
+
+#[test]
+fn keyword_colors() {
+    assert_eq!(keywords("red on-blue"), RedFg.color(BlueBg));
+    assert_eq!(
+        keywords("bright-red on-bright-white ul-default"),
+        BrightRedFg.color(BrightWhiteBg).color(DefaultUl)
+    );
+    assert_eq!(keywords("default on-default"), DefaultFg.color(DefaultBg));
+    assert_eq!(keywords("on-15"), ByteBg(15).into());
+    assert_eq!(keywords("15,115,215"), RgbFg(15, 115, 215).into());
+    assert_eq!(keywords("#0f"), ByteFg(15).into());
+    assert_eq!(keywords("on-#0f73d7"), RgbBg(15, 115, 215).into());
+    assert_eq!(keywords("ul-208"), ByteUl(208).into());
+
+    let sgr = keywords("italic red on-#0f73d7");
+    assert_eq!(sgr.italic, StyleKind::Place);
+    assert_eq!(sgr.foreground, ColorKind::Red);
+    assert_eq!(sgr.background, ColorKind::Rgb(15, 115, 215));
+}
+
+#[test]
+fn keyword_errors() {
+    use ParseKeywordsError::*;
+    for word in ["Bold", "wavy", "on-", "!red", "bright-"] {
+        assert_eq!(SGRString::from_keywords(word), Err(Keyword(word.into())));
+    }
+    for word in ["1,2", "1,2,3,4", "#f", "#0f73d", "on-#"] {
+        assert_eq!(SGRString::from_keywords(word), Err(ColorLen(word.into())));
+    }
+    let err = SGRString::from_keywords("bold 256").unwrap_err();
+    assert!(matches!(&err, Int(word, _) if word == "256"));
+    assert_eq!(
+        err.to_string(),
+        "number too large to fit in target type in color: 256"
+    );
+    assert!(err.source().is_some());
+    assert!(matches!(
+        SGRString::from_keywords("#zz"),
+        Err(Int(word, _)) if word == "#zz"
+    ));
+}
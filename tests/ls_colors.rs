@@ -0,0 +1,239 @@
+use std::{error::Error, fs, path::PathBuf};
+
+use easy_sgr::{
+    CleanKind, Color::*, EasySGR, FileKind, LsColors, ParseLsColorsError, SGRString, Style::*,
+};
+
+fn style(codes: &str) -> SGRString {
+    SGRString::from_codes(codes)
+        .unwrap()
+        .clean(CleanKind::Reset)
+}
+
+#[test]
+fn parse() {
+    let colors: LsColors = "no=01:fi=02:di=01;35:*.rs=38;5;208:lc=\\e[:*README=4::"
+        .parse()
+        .unwrap();
+    assert_eq!(colors.get(FileKind::Normal), Some(&style("01")));
+    assert_eq!(colors.get(FileKind::File), Some(&style("02")));
+    assert_eq!(
+        colors.get(FileKind::Directory),
+        Some(&Bold.color(MagentaFg).clean(CleanKind::Reset))
+    );
+    // defaults are kept when not overridden
+    assert_eq!(colors.get(FileKind::Link), Some(&style("01;36")));
+    assert_eq!(colors.style_for_name("main.rs"), Some(&style("38;5;208")));
+    assert_eq!(colors.style_for_name("README"), Some(&style("4")));
+    assert_eq!(colors.style_for_name("rs"), None);
+
+    assert_eq!(LsColors::new().get(FileKind::Directory), None);
+    assert_eq!("ca".parse(), Ok(FileKind::Capability));
+    let err: Box<dyn Error> = "zz".parse::<FileKind>().unwrap_err().into();
+    assert_eq!(err.to_string(), "Unrecognised file kind indicator");
+    assert_eq!(FileKind::StickyOtherWritable.indicator(), "tw");
+}
+
+#[test]
+fn suffixes() {
+    let colors: LsColors = "*.tar=31:*.TAR=32:*.gz=33:*.tar.gz=34".parse().unwrap();
+    assert_eq!(colors.style_for_name("a.tar"), Some(&style("31")));
+    assert_eq!(colors.style_for_name("a.TAR"), Some(&style("32")));
+    assert_eq!(colors.style_for_name("a.Tar"), Some(&style("32")));
+    assert_eq!(colors.style_for_name("a.tar.gz"), Some(&style("34")));
+    assert_eq!(colors.style_for_name("a.gz"), Some(&style("33")));
+
+    let colors: LsColors = "*.gz=33:*.gz=35".parse().unwrap();
+    assert_eq!(colors.style_for_name("a.gz"), Some(&style("35")));
+}
+
+#[test]
+fn kinds() {
+    let colors: LsColors = "no=2:fi=3:*.rs=4:or=5".parse().unwrap();
+    assert_eq!(
+        colors.style_for_kind(FileKind::File, "main.rs"),
+        Some(&style("4"))
+    );
+    assert_eq!(
+        colors.style_for_kind(FileKind::File, "main.c"),
+        Some(&style("3"))
+    );
+    assert_eq!(
+        colors.style_for_kind(FileKind::Orphan, "link"),
+        Some(&style("5"))
+    );
+    assert_eq!(
+        colors.style_for_kind(FileKind::Missing, "gone"),
+        Some(&style("2"))
+    );
+    assert_eq!(
+        LsColors::default().style_for_kind(FileKind::Orphan, "link"),
+        Some(&style("01;36"))
+    );
+}
+
+#[test]
+fn uncolored_kinds() {
+    // kinds set to `00`, `0` or nothing are not styled, as `dircolors` writes them
+    let colors: LsColors = "no=00:fi=:mh=00:ca=0:mi=00:ex=00:rs=0:*.rs=31"
+        .parse()
+        .unwrap();
+    for kind in [
+        FileKind::Normal,
+        FileKind::File,
+        FileKind::MultiHardLink,
+        FileKind::Capability,
+        FileKind::Missing,
+        FileKind::Executable,
+    ] {
+        assert_eq!(colors.get(kind), None);
+    }
+    assert_eq!(colors.get(FileKind::Reset), Some(&style("0")));
+    assert_eq!(
+        colors.style_for_kind(FileKind::MultiHardLink, "main.rs"),
+        Some(&style("31"))
+    );
+    assert_eq!(
+        colors.style_for_kind(FileKind::Executable, "main.rs"),
+        Some(&style("31"))
+    );
+    assert_eq!(colors.style_for_kind(FileKind::Missing, "gone"), None);
+}
+
+#[test]
+fn directory_kinds() {
+    let colors: LsColors = "tw=:ow=:st=:di=34".parse().unwrap();
+    for kind in [
+        FileKind::StickyOtherWritable,
+        FileKind::OtherWritable,
+        FileKind::Sticky,
+    ] {
+        assert_eq!(colors.style_for_kind(kind, "dir"), Some(&style("34")));
+    }
+    let colors: LsColors = "tw=00:ow=32".parse().unwrap();
+    assert_eq!(
+        colors.style_for_kind(FileKind::StickyOtherWritable, "dir"),
+        Some(&style("32"))
+    );
+    let colors: LsColors = "tw=00:ow=00".parse().unwrap();
+    assert_eq!(
+        colors.style_for_kind(FileKind::StickyOtherWritable, "dir"),
+        Some(&style("37;44"))
+    );
+}
+
+#[test]
+fn regular_kinds() {
+    let mut colors = LsColors::new();
+    colors.load("*.rs=38;5;208:mh=44").unwrap();
+    // regular files are styled by their suffix when their kind has no style
+    for kind in [
+        FileKind::Setuid,
+        FileKind::Setgid,
+        FileKind::Capability,
+        FileKind::Executable,
+    ] {
+        assert_eq!(
+            colors.style_for_kind(kind, "a.rs"),
+            Some(&style("38;5;208"))
+        );
+        assert_eq!(colors.style_for_kind(kind, "a.c"), None);
+    }
+    assert_eq!(
+        colors.style_for_kind(FileKind::MultiHardLink, "a.rs"),
+        Some(&style("44"))
+    );
+
+    let colors: LsColors = "fi=35:*.rs=33".parse().unwrap();
+    assert_eq!(
+        colors.style_for_kind(FileKind::Executable, "a.rs"),
+        Some(&style("01;32"))
+    );
+    assert_eq!(
+        colors.style_for_kind(FileKind::MultiHardLink, "a.rs"),
+        Some(&style("33"))
+    );
+    assert_eq!(
+        colors.style_for_kind(FileKind::MultiHardLink, "a.c"),
+        Some(&style("35"))
+    );
+}
+
+#[test]
+fn precedence() {
+    // later suffixes are preferred, even when shorter
+    let colors: LsColors = "*.tar.gz=34:*.gz=33".parse().unwrap();
+    assert_eq!(colors.style_for_name("a.tar.gz"), Some(&style("33")));
+    // matching case is preferred over later suffixes
+    let colors: LsColors = "*.TAR=32:*.tar=31".parse().unwrap();
+    assert_eq!(colors.style_for_name("a.TAR"), Some(&style("32")));
+    assert_eq!(colors.style_for_name("a.Tar"), Some(&style("31")));
+    // suffixes are not limited to extensions
+    let colors: LsColors = "*Makefile=35".parse().unwrap();
+    assert_eq!(colors.style_for_name("GNUmakefile"), Some(&style("35")));
+
+    let colors: LsColors = "ln=target:ln=33".parse().unwrap();
+    assert_eq!(colors.get(FileKind::Link), Some(&style("33")));
+    let colors: LsColors = "lc=\\e[:rc=m:ec=:cl=".parse().unwrap();
+    assert_eq!(colors, LsColors::default());
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        "di=1:broken".parse::<LsColors>(),
+        Err(ParseLsColorsError::Separator("broken".into()))
+    );
+    assert_eq!(
+        "DI=1".parse::<LsColors>(),
+        Err(ParseLsColorsError::Key("DI".into()))
+    );
+    assert_eq!(
+        "*.rs=red".parse::<LsColors>().unwrap_err().to_string(),
+        "Invalid SGR parameter: red for key: *.rs"
+    );
+    let mut colors = LsColors::new();
+    assert!(colors.load("*.rs=31:ln=target:zz=1").is_err());
+    assert_eq!(colors, LsColors::new());
+}
+
+#[test]
+#[cfg(unix)]
+fn paths() -> Result<(), Box<dyn Error>> {
+    use std::os::unix::fs::{symlink, PermissionsExt};
+
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join("ls_colors");
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir)?;
+    fs::write(dir.join("main.rs"), "")?;
+    fs::write(dir.join("run"), "")?;
+    fs::set_permissions(dir.join("run"), fs::Permissions::from_mode(0o755))?;
+    symlink(dir.join("main.rs"), dir.join("link"))?;
+    symlink(dir.join("gone"), dir.join("orphan"))?;
+
+    let colors: LsColors = "*.rs=38;5;208:or=31".parse()?;
+    assert_eq!(colors.style_for_path(&dir), Some(&style("01;34")));
+    assert_eq!(
+        colors.style_for_path(dir.join("main.rs")),
+        Some(&style("38;5;208"))
+    );
+    assert_eq!(
+        colors.style_for_path(dir.join("run")),
+        Some(&style("01;32"))
+    );
+    assert_eq!(
+        colors.style_for_path(dir.join("link")),
+        Some(&style("01;36"))
+    );
+    assert_eq!(
+        colors.style_for_path(dir.join("orphan")),
+        Some(&style("31"))
+    );
+    assert_eq!(colors.style_for_path(dir.join("gone")), None);
+
+    let colors: LsColors = "ln=target:*.rs=33".parse()?;
+    assert_eq!(colors.style_for_path(dir.join("link")), Some(&style("33")));
+
+    fs::remove_dir_all(&dir)?;
+    Ok(())
+}
@@ -76,6 +76,42 @@ mod macros {
         );
     }
 
+    #[test]
+    fn bright_colors() {
+        sgr_tests!(
+            "{[bright-black]}" = "\x1b[90m",
+            "{[bright-red]}" = "\x1b[91m",
+            "{[bright-green]}" = "\x1b[92m",
+            "{[bright-yellow]}" = "\x1b[93m",
+            "{[bright-blue]}" = "\x1b[94m",
+            "{[bright-magenta]}" = "\x1b[95m",
+            "{[bright-cyan]}" = "\x1b[96m",
+            "{[bright-white]}" = "\x1b[97m",
+            "{[on-bright-black]}" = "\x1b[100m",
+            "{[on-bright-red]}" = "\x1b[101m",
+            "{[on-bright-green]}" = "\x1b[102m",
+            "{[on-bright-yellow]}" = "\x1b[103m",
+            "{[on-bright-blue]}" = "\x1b[104m",
+            "{[on-bright-magenta]}" = "\x1b[105m",
+            "{[on-bright-cyan]}" = "\x1b[106m",
+            "{[on-bright-white]}" = "\x1b[107m"
+        );
+    }
+
+    #[test]
+    fn underline() {
+        sgr_tests!(
+            "{[double-underline]}" = "\x1b[4:2m",
+            "{[curly-underline]}" = "\x1b[4:3m",
+            "{[dotted-underline]}" = "\x1b[4:4m",
+            "{[dashed-underline]}" = "\x1b[4:5m",
+            "{[ul-default]}" = "\x1b[59m",
+            "{[ul-208]}" = "\x1b[58;5;208m",
+            "{[ul-#ff8000]}" = "\x1b[58;2;255;128;0m",
+            "{[curly-underline ul-255,0,0]}" = "\x1b[4:3;58;2;255;0;0m"
+        );
+    }
+
     #[test]
     fn byte_color() {
         sgr_tests!(
@@ -0,0 +1,319 @@
+use std::{error::Error, io::Write};
+
+use easy_sgr::{
+    strip_sgr, CleanKind, Color::*, ColorKind, EasySGR, FontKind, IdeogramKind, SGRParser,
+    SGRString, StripWriter, Style::*, StyleKind,
+};
+
+fn parse(s: &str) -> Vec<SGRString> {
+    SGRParser::new(s).collect()
+}
+
+#[test]
+fn plain() {
+    assert_eq!(parse(""), vec![]);
+    assert_eq!(parse("test"), vec![SGRString::from("test")]);
+    assert_eq!(
+        parse("\x1b[2Knot sgr\x1b[1;2"),
+        vec![SGRString::from("\x1b[2Knot sgr\x1b[1;2")]
+    );
+}
+
+#[test]
+fn spans() {
+    assert_eq!(
+        parse("a\x1b[1mb\x1b[31mc\x1b[0m"),
+        vec![
+            SGRString::from("a"),
+            "b".style(Bold),
+            "c".style(Bold).color(RedFg),
+            SGRString {
+                reset: true,
+                ..Default::default()
+            },
+        ]
+    );
+    assert_eq!(
+        parse("\x1b[m\x1b[1;;3mtest"),
+        vec![SGRString {
+            text: String::from("test"),
+            reset: true,
+            italic: StyleKind::Place,
+            ..Default::default()
+        }]
+    );
+}
+
+#[test]
+fn colors() {
+    for color in [
+        ColorKind::Black,
+        ColorKind::Red,
+        ColorKind::Green,
+        ColorKind::Yellow,
+        ColorKind::Blue,
+        ColorKind::Magenta,
+        ColorKind::Cyan,
+        ColorKind::White,
+        ColorKind::BrightBlack,
+        ColorKind::BrightRed,
+        ColorKind::BrightGreen,
+        ColorKind::BrightYellow,
+        ColorKind::BrightBlue,
+        ColorKind::BrightMagenta,
+        ColorKind::BrightCyan,
+        ColorKind::BrightWhite,
+        ColorKind::Byte(208),
+        ColorKind::Rgb(15, 115, 215),
+        ColorKind::Default,
+    ] {
+        let sgr = SGRString {
+            text: String::from("test"),
+            foreground: color.clone(),
+            background: color,
+            ..Default::default()
+        };
+        assert_eq!(parse(&sgr.to_string()), vec![sgr]);
+    }
+}
+
+#[test]
+fn custom() {
+    assert_eq!(parse("\x1b[99mtest"), vec!["test".custom(99)]);
+    assert_eq!(
+        parse("\x1b[38;5mtest\x1b[48;7;1m"),
+        vec![
+            "test".custom(38).custom(5),
+            "".custom(38).custom(5).custom(48).custom(7).style(Bold),
+        ]
+    );
+}
+
+#[test]
+fn round_trip() {
+    let sgr = SGRString {
+        text: "test".to_string(),
+        clean: CleanKind::Reverse,
+        custom_places: vec![99],
+        foreground: ColorKind::Rgb(1, 2, 3),
+        background: ColorKind::Byte(4),
+        reset: true,
+        bold: StyleKind::Place,
+        dim: StyleKind::Place,
+        italic: StyleKind::Place,
+        underline: StyleKind::Place,
+        blinking: StyleKind::Place,
+        inverse: StyleKind::Place,
+        hidden: StyleKind::Place,
+        strikethrough: StyleKind::Place,
+        ..Default::default()
+    };
+    let parsed = parse(&sgr.to_string());
+    assert_eq!(
+        parsed[0],
+        SGRString {
+            clean: CleanKind::None,
+            ..sgr.clone()
+        }
+    );
+    assert_eq!(
+        parsed[1],
+        SGRString {
+            text: String::new(),
+            clean: CleanKind::None,
+            foreground: ColorKind::Default,
+            background: ColorKind::Default,
+            bold: StyleKind::Clean,
+            dim: StyleKind::Clean,
+            italic: StyleKind::Clean,
+            underline: StyleKind::Clean,
+            blinking: StyleKind::Clean,
+            inverse: StyleKind::Clean,
+            hidden: StyleKind::Clean,
+            strikethrough: StyleKind::Clean,
+            ..sgr
+        }
+    );
+    assert_eq!(parsed.len(), 2);
+
+    let rendered: String = parsed.iter().map(ToString::to_string).collect();
+    assert_eq!(parse(&rendered), parsed);
+}
+
+#[test]
+fn state() {
+    let mut parser = SGRParser::from("\x1b[3mtest\x1b[23m");
+    parser.next();
+    assert_eq!(parser.state(), &"".style(Italic));
+    parser.next();
+    assert_eq!(parser.state(), &"".style(NotItalic));
+}
+
+#[test]
+fn strip() {
+    for (test, result) in [
+        ("", ""),
+        ("test", "test"),
+        ("\x1b[31;1mtest\x1b[0m", "test"),
+        ("\x1b[mte\x1b[38;5;208mst", "test"),
+        ("\x1b[4:3mtest\x1b[300m", "test"),
+        ("\x1b[2K\x1b[1;2\x1b\x1b[m☀", "\x1b[2K\x1b[1;2\x1b☀"),
+    ] {
+        assert_eq!(strip_sgr(test), result);
+    }
+}
+
+#[test]
+fn strip_writer() -> Result<(), Box<dyn Error>> {
+    let test = "\x1b[0;31;41;1mtest\x1b[2K\x1b\x1b[1\x1b[39;49;22m ☀\x1b[";
+    let result = "test\x1b[2K\x1b\x1b[1 ☀\x1b[";
+
+    for size in 1..test.len() {
+        let mut writer = StripWriter::new(Vec::new());
+        for chunk in test.as_bytes().chunks(size) {
+            writer.write_all(chunk)?;
+        }
+        writer.flush()?;
+        assert_eq!(writer.get_ref().len(), result.len() - 2);
+        assert_eq!(writer.finish()?, result.as_bytes());
+    }
+    let sgr = "test".color(RedFg).style(Bold).clean(CleanKind::Reverse);
+    let mut writer = StripWriter::new(Vec::new());
+    write!(writer, "{sgr}{}", Italic.to_sgr())?;
+    assert_eq!(writer.finish()?, b"test");
+    Ok(())
+}
+
+#[test]
+fn underline() {
+    assert_eq!(
+        parse("\x1b[4:3;58;5;208mtest"),
+        vec!["test".style(CurlyUnderline).color(ByteUl(208))]
+    );
+    assert_eq!(
+        parse("\x1b[4:3;4mtest\x1b[59;4:0m"),
+        vec![
+            "test".style(Underline),
+            SGRString {
+                underline: StyleKind::Clean,
+                underline_color: ColorKind::Default,
+                ..Default::default()
+            }
+        ]
+    );
+    assert_eq!(parse("\x1b[4:2:1mtest"), vec!["\x1b[4:2:1mtest".to_sgr()]);
+    assert_eq!(parse("\x1b[4:5mtest"), vec!["test".style(DashedUnderline)]);
+    // each underline replaces the previous, doubly underlined included
+    assert_eq!(
+        parse("\x1b[21m\x1b[4:3mte\x1b[21mst"),
+        vec!["te".style(CurlyUnderline), "st".style(DoublyUnderlined)]
+    );
+    assert_eq!(
+        parse("\x1b[21;4:0mtest"),
+        vec![SGRString {
+            text: "test".into(),
+            underline: StyleKind::Clean,
+            ..Default::default()
+        }]
+    );
+    // unknown underline styles are ignored
+    assert_eq!(parse("\x1b[4:9;1mtest"), vec!["test".style(Bold)]);
+    assert_eq!(
+        parse("\x1b[4:3mte\x1b[4:6mst"),
+        vec!["te".style(CurlyUnderline), "st".style(CurlyUnderline)]
+    );
+}
+
+#[test]
+fn sub_params() {
+    let correct = vec!["test"
+        .color(RgbFg(1, 2, 3))
+        .color(ByteBg(4))
+        .color(RgbUl(5, 6, 7))];
+    assert_eq!(parse("\x1b[38:2::1:2:3;48:5:4;58:2:5:6:7mtest"), correct);
+    assert_eq!(parse("\x1b[38;2;1;2;3;48;5;4;58:2::5:6:7mtest"), correct);
+    assert_eq!(
+        parse("\x1b[38:2:1:2mtest"),
+        vec!["\x1b[38:2:1:2mtest".to_sgr()]
+    );
+    // a sub-parameter ends an incomplete color, then is applied on its own
+    assert_eq!(
+        parse("\x1b[38;4:3mx"),
+        vec!["x".custom(38).style(CurlyUnderline)]
+    );
+    assert_eq!(
+        parse("\x1b[48;2;1;4:3mx"),
+        vec!["x".custom(48).custom(2).custom(1).style(CurlyUnderline)]
+    );
+}
+
+#[test]
+fn styles() {
+    let sgr = SGRString {
+        text: String::from("test"),
+        doubly_underlined: StyleKind::Place,
+        proportional_spacing: StyleKind::Place,
+        framed: StyleKind::Place,
+        encircled: StyleKind::Place,
+        overline: StyleKind::Place,
+        superscript: StyleKind::Place,
+        font: FontKind::Alternate(3),
+        ideogram: IdeogramKind::Stress,
+        ..Default::default()
+    };
+    assert_eq!(parse(&sgr.to_string()), vec![sgr]);
+
+    let sgr = SGRString {
+        text: String::from("test"),
+        proportional_spacing: StyleKind::Clean,
+        framed: StyleKind::Clean,
+        encircled: StyleKind::Clean,
+        overline: StyleKind::Clean,
+        superscript: StyleKind::Clean,
+        subscript: StyleKind::Clean,
+        font: FontKind::Primary,
+        ideogram: IdeogramKind::Clean,
+        ..Default::default()
+    };
+    assert_eq!(parse(&sgr.to_string()), vec![sgr]);
+    assert_eq!(
+        parse("\x1b[4;21;73;74;20mtest"),
+        vec!["test"
+            .style(DoublyUnderlined)
+            .style(Subscript)
+            .style(Fraktur)]
+    );
+}
+
+#[test]
+fn from_codes() {
+    assert_eq!(SGRString::from_codes(""), Ok(SGRString::default()));
+    assert_eq!(
+        SGRString::from_codes("01;31"),
+        Ok(Bold.color(RedFg).to_sgr())
+    );
+    assert_eq!(
+        SGRString::from_codes("4:3;48;2;1;2;3"),
+        Ok(CurlyUnderline.color(RgbBg(1, 2, 3)))
+    );
+    assert_eq!(SGRString::from_codes("0").map(|sgr| sgr.reset), Ok(true));
+    assert_eq!(
+        SGRString::from_codes("38;5;208;99"),
+        Ok(ByteFg(208).custom(99u8))
+    );
+
+    let err = SGRString::from_codes("1;x;2").unwrap_err();
+    assert_eq!(err.0, "x");
+    assert_eq!(err.to_string(), "Invalid SGR parameter: x");
+    assert!(SGRString::from_codes("1;256").is_err());
+}
+
+#[test]
+fn to_codes() {
+    assert_eq!(SGRString::default().to_codes(), "");
+    assert_eq!(Reset.to_sgr().to_codes(), "0");
+    for codes in ["0;31;1", "4:3;48;2;1;2;3", "38;5;208;99"] {
+        let sgr = SGRString::from_codes(codes).unwrap();
+        assert_eq!(SGRString::from_codes(&sgr.to_codes()), Ok(sgr));
+    }
+}
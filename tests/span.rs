@@ -0,0 +1,58 @@
+use easy_sgr::{CleanKind, Color::*, EasySGR, SGRSpan, SGRWriter, SpanChild, Style::*};
+
+#[test]
+fn nested() {
+    let span = SGRSpan::new(RedFg.text("red "))
+        .span(Bold.color(BlueFg).text("bold blue"))
+        .text(" red");
+    assert_eq!(
+        "\x1b[31mred \x1b[34;1mbold blue\x1b[31;22m red\x1b[39m",
+        span.to_string()
+    );
+
+    let span = SGRSpan::new(Dim)
+        .text("dim ")
+        .span(SGRSpan::new(Bold).text("bold ").span(Italic.text("italic")))
+        .text(" dim");
+    assert_eq!(
+        "\x1b[2mdim \x1b[1mbold \x1b[3mitalic\x1b[23m\x1b[22;2m dim\x1b[22m",
+        span.to_string()
+    );
+}
+
+#[test]
+fn children() {
+    let span = SGRSpan::default().text("plain").span("nested");
+    assert_eq!(
+        span.children,
+        [
+            SpanChild::from("plain"),
+            SpanChild::from(SGRSpan::new("nested"))
+        ]
+    );
+    assert_eq!("plainnested", span.to_string());
+    assert_eq!(SGRSpan::new(Bold).children, []);
+}
+
+#[test]
+fn ignored() {
+    // the clean of the style is ignored, the parent is always restored
+    let span = SGRSpan::new(Italic).span(Bold.clean(CleanKind::Reset).text("bold"));
+    assert_eq!("\x1b[3m\x1b[1mbold\x1b[22m\x1b[23m", span.to_string());
+}
+
+#[test]
+fn reset() {
+    let span = SGRSpan::new(RedFg.style(Bold))
+        .span(Reset.color(GreenFg).text("green"))
+        .text("red");
+    assert_eq!(
+        "\x1b[31;1m\x1b[0;32mgreen\x1b[0;31;1mred\x1b[39;22m",
+        span.to_string()
+    );
+
+    let mut w = SGRWriter::from(String::new());
+    w.enabled = false;
+    span.write_to(&mut w).unwrap();
+    assert_eq!("greenred", w.internal());
+}
@@ -0,0 +1,115 @@
+use std::error::Error;
+
+use easy_sgr::{
+    Color::*, ColorKind, EasySGR, FontKind, Param, SGRState, SGRString, SGRWriter, Style::*,
+    UnderlineKind,
+};
+
+fn diff(prev: impl Into<SGRString>, next: impl Into<SGRString>) -> Vec<Param> {
+    SGRState::from(&prev.into())
+        .diff(&SGRState::from(&next.into()))
+        .0
+}
+
+#[test]
+fn from_sgr() {
+    let state = SGRState::from(&Bold.color(DefaultFg).style(NotDim).style(CurlyUnderline));
+    assert_eq!(
+        state,
+        SGRState {
+            bold: true,
+            underline: UnderlineKind::Curly,
+            ..Default::default()
+        }
+    );
+    assert_eq!(
+        SGRState::from(&Underline.style(PrimaryFont).color(RedBg)),
+        SGRState {
+            background: ColorKind::Red,
+            underline: UnderlineKind::Single,
+            ..Default::default()
+        }
+    );
+    let state = SGRState {
+        foreground: ColorKind::Byte(208),
+        underline: UnderlineKind::Dotted,
+        font: FontKind::Fraktur,
+        dim: true,
+        ..Default::default()
+    };
+    assert_eq!(state, SGRState::from(&SGRString::from(&state)));
+}
+
+#[test]
+fn apply() {
+    let mut state = SGRState::default();
+    state.apply(&[Param::Code(1), Param::Code(2), Param::Byte(38, 208)]);
+    assert!(state.bold && state.dim);
+    assert_eq!(state.foreground, ColorKind::Byte(208));
+
+    state.apply(&[Param::Code(22), Param::Sub(4, 3), Param::Code(39)]);
+    assert_eq!(
+        state,
+        SGRState {
+            underline: UnderlineKind::Curly,
+            ..Default::default()
+        }
+    );
+    state.apply(&[Param::Code(0)]);
+    assert_eq!(state, SGRState::default());
+}
+
+#[test]
+fn minimal() {
+    use Param::*;
+    assert_eq!(diff(Bold, Bold), []);
+    assert_eq!(diff(SGRString::default(), Bold), [Code(1)]);
+    assert_eq!(diff(Bold, SGRString::default()), [Code(0)]);
+    assert_eq!(diff(Bold.style(Italic), Italic), [Code(22)]);
+    assert_eq!(
+        diff(Bold.style(Dim).color(RedFg), Dim.color(RedFg)),
+        [Code(22), Code(2)]
+    );
+    assert_eq!(diff(Bold, Bold.color(RedFg)), [Code(31)]);
+    assert_eq!(diff(RedFg.style(Italic), BlueFg.style(Italic)), [Code(34)]);
+    assert_eq!(
+        diff(Framed.style(Encircled).style(Bold), Encircled.style(Bold)),
+        [Code(54), Code(52)]
+    );
+    assert_eq!(diff(CurlyUnderline, DashedUnderline), [Sub(4, 5)]);
+    assert_eq!(diff(CurlyUnderline, Underline), [Code(4)]);
+    assert_eq!(diff(Fraktur.style(Italic), Italic), [Code(10)]);
+    assert_eq!(diff(ByteUl(1), RgbUl(1, 2, 3)), [Rgb(58, 1, 2, 3)]);
+}
+
+#[test]
+fn reset() {
+    use Param::*;
+    let full = Bold
+        .style(Italic)
+        .style(Underline)
+        .color(RedFg)
+        .color(BlueBg);
+    assert_eq!(diff(full.clone(), SGRString::default()), [Code(0)]);
+    assert_eq!(diff(full, Dim), [Code(0), Code(2)]);
+    // as many codes either way, `0` is shorter than `22`
+    assert_eq!(
+        diff(Bold.color(ByteFg(1)), ByteFg(100)),
+        [Code(0), Byte(38, 100)]
+    );
+    assert_eq!(
+        diff(Bold.color(RgbFg(1, 2, 3)), Bold.color(RgbFg(100, 200, 255))),
+        [Rgb(38, 100, 200, 255)]
+    );
+}
+
+#[test]
+fn writer() -> Result<(), Box<dyn Error>> {
+    let mut w = SGRWriter::from(String::new());
+    let prev = SGRState::from(&Bold.color(RedFg));
+    let next = SGRState::from(&Bold.color(BlueFg));
+    w.diff_sgr(&prev, &next)?;
+    w.diff_sgr(&next, &next)?;
+    assert_eq!("\x1b[34m", w.internal());
+    Ok(())
+}
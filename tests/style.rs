@@ -0,0 +1,153 @@
+use easy_sgr::{
+    CleanKind, Color::*, ColorKind, EasySGR, FontKind, SGRString, SGRStyle, Style::*, StyleKind,
+    UnderlineKind,
+};
+
+const PLAIN: SGRStyle = SGRStyle::new();
+const LOADED: SGRStyle = SGRStyle::new()
+    .style(Reset)
+    .style(Bold)
+    .style(NotItalic)
+    .style(CurlyUnderline)
+    .style(AlternateFont(3))
+    .color(BrightRedFg)
+    .color(ByteBg(208))
+    .color(RgbUl(1, 2, 3))
+    .custom_place(60)
+    .custom_clean(65)
+    .clean(CleanKind::Reverse);
+
+#[test]
+fn escapes() {
+    const PLACE: &str = LOADED.place_escape().as_str();
+    const CLEAN: &str = LOADED.clean_escape().as_str();
+    assert_eq!(PLACE, "\x1b[0;91;48;5;208;58;2;1;2;3;1;23;4:3;13;60m");
+    assert_eq!(CLEAN, "\x1b[39;49;59;22;3;24;10;65m");
+    assert_eq!(PLAIN.place_escape().as_str(), "");
+    assert_eq!(PLAIN.clean_escape().as_str(), "");
+    assert_eq!(
+        SGRStyle::new()
+            .clean(CleanKind::Reset)
+            .clean_escape()
+            .as_str(),
+        "\x1b[0m"
+    );
+    #[cfg(not(feature = "partial"))]
+    let expected = "\x1b[40m";
+    #[cfg(feature = "partial")]
+    let expected = "40";
+    assert_eq!(SGRStyle::new().color(BlackBg).to_string(), expected);
+    assert_eq!(PLAIN.to_string(), "");
+}
+
+#[test]
+fn matches_sgr_string() {
+    let string = SGRString::from(LOADED);
+    assert_eq!(string.foreground, ColorKind::BrightRed);
+    assert_eq!(string.underline_style, UnderlineKind::Curly);
+    assert_eq!(string.italic, StyleKind::Clean);
+    assert_eq!(string.font, FontKind::Alternate(3));
+    assert_eq!(string.custom_places, [60]);
+    assert_eq!(
+        string.text("text").to_string(),
+        format!(
+            "{}text{}",
+            LOADED.place_escape().as_str(),
+            LOADED.clean_escape().as_str()
+        )
+    );
+    assert_eq!(
+        LOADED.text("text"),
+        Reset
+            .style(Bold)
+            .style(NotItalic)
+            .style(CurlyUnderline)
+            .style(AlternateFont(3))
+            .color(BrightRedFg)
+            .color(ByteBg(208))
+            .color(RgbUl(1, 2, 3))
+            .custom_place(60u8)
+            .custom_clean(65u8)
+            .clean(CleanKind::Reverse)
+            .text("text")
+    );
+    assert_eq!(SGRString::from(PLAIN), SGRString::default());
+}
+
+#[test]
+#[should_panic = "too many custom codes"]
+fn custom_capacity() {
+    let mut style = SGRStyle::new();
+    for code in 0..=SGRStyle::CUSTOM_CAPACITY as u8 {
+        style = style.custom_place(code);
+    }
+}
+
+#[test]
+fn every_attribute() {
+    let styles = [
+        Reset,
+        Bold,
+        Dim,
+        Italic,
+        Underline,
+        Blinking,
+        Inverse,
+        Hidden,
+        Strikethrough,
+        DoubleUnderline,
+        CurlyUnderline,
+        DottedUnderline,
+        DashedUnderline,
+        DoublyUnderlined,
+        ProportionalSpacing,
+        Framed,
+        Encircled,
+        Overline,
+        PrimaryFont,
+        AlternateFont(2),
+        Fraktur,
+        IdeogramUnderline,
+        IdeogramDoubleUnderline,
+        IdeogramOverline,
+        IdeogramDoubleOverline,
+        IdeogramStress,
+        Superscript,
+        Subscript,
+        NotBold,
+        NotDim,
+        NotItalic,
+        NotUnderline,
+        NotBlinking,
+        NotInverse,
+        NotHidden,
+        NotStrikethrough,
+        NotProportionalSpacing,
+        NotFramed,
+        NotEncircled,
+        NotOverline,
+        NotIdeogram,
+        NotSuperscript,
+        NotSubscript,
+    ];
+    for style in styles {
+        let sgr = SGRStyle::new()
+            .style(style.clone())
+            .clean(CleanKind::Reverse);
+        let string = style.clean(CleanKind::Reverse).text("text");
+        assert_eq!((&sgr).text("text"), string);
+        assert_eq!(
+            format!(
+                "{}text{}",
+                sgr.place_escape().as_str(),
+                sgr.clean_escape().as_str()
+            ),
+            string.to_string()
+        );
+    }
+    for color in [RedFg, BrightWhiteBg, ByteFg(208), RgbBg(1, 2, 3), DefaultUl] {
+        let sgr = SGRStyle::new().color(color.clone());
+        assert_eq!(sgr.to_string(), color.to_string());
+        assert_eq!(sgr.text("text"), color.text("text"));
+    }
+}
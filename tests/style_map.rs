@@ -0,0 +1,81 @@
+use easy_sgr::{CleanKind, Color::*, EasySGR, ParseStyleMapError, SGRString, Style::*, StyleMap};
+
+#[test]
+fn gcc() {
+    let map: StyleMap = "error=01;31:warning=01;35:note=01;36:caret=01;32:locus=01:quote=01"
+        .parse()
+        .unwrap();
+    assert_eq!(map.iter().count(), 6);
+    assert_eq!(
+        map.get("error"),
+        Some(&Bold.color(RedFg).clean(CleanKind::Reset))
+    );
+    assert_eq!(
+        "\x1b[1mfile.c:1:1:\x1b[0m",
+        map.paint("locus", "file.c:1:1:").to_string()
+    );
+    assert_eq!("plain", map.paint("missing", "plain").to_string());
+}
+
+#[test]
+fn grep() {
+    let mut map = StyleMap::new();
+    map.set("ms", Bold.color(RedFg));
+    map.set("fn", MagentaFg);
+    map.load("ms=04;32:sl=:cx=::rv:ne").unwrap();
+    assert_eq!(
+        map.get("ms"),
+        Some(&Underline.color(GreenFg).clean(CleanKind::Reset))
+    );
+    assert_eq!(map.get("fn"), Some(&MagentaFg.clean(CleanKind::Reset)));
+    // an empty style still resets after itself
+    assert_eq!("plain\x1b[0m", map.paint("sl", "plain").to_string());
+    assert!(map.has_flag("rv") && map.has_flag("ne"));
+    assert!(!map.has_flag("ms"));
+    assert!(map.flags().eq(["ne", "rv"]));
+
+    assert_eq!("cx=:fn=35:ms=32;4:sl=:ne:rv", map.to_string());
+    assert_eq!(Ok(map.clone()), map.to_string().parse());
+}
+
+#[test]
+fn env() {
+    let mut map: StyleMap = "error=31:ne".parse().unwrap();
+    let before = map.clone();
+    map.load_env("EASY_SGR_TEST_UNSET").unwrap();
+    assert_eq!(map, before);
+    std::env::set_var("EASY_SGR_TEST_STYLE_MAP", "error=01:note=36");
+    map.load_env("EASY_SGR_TEST_STYLE_MAP").unwrap();
+    assert_eq!(map.get("error"), Some(&Bold.clean(CleanKind::Reset)));
+    assert_eq!(map.get("note"), Some(&CyanFg.clean(CleanKind::Reset)));
+    assert!(map.has_flag("ne"));
+}
+
+#[test]
+fn entries() {
+    let map: StyleMap = "ne::rv:ne:".parse().unwrap();
+    assert_eq!(map.iter().count(), 0);
+    assert!(map.flags().eq(["ne", "rv"]));
+    assert_eq!("ne:rv", map.to_string());
+
+    // a key can be both a flag & a style
+    let map: StyleMap = "mt=:mt".parse().unwrap();
+    assert_eq!(
+        map.get("mt"),
+        Some(&SGRString::default().clean(CleanKind::Reset))
+    );
+    assert!(map.has_flag("mt"));
+    assert_eq!("mt=:mt", map.to_string());
+
+    let mut map: StyleMap = "error=31".parse().unwrap();
+    assert_eq!(
+        map.load("note=36:=1"),
+        Err(ParseStyleMapError::Key("=1".into()))
+    );
+    assert_eq!(map.load("="), Err(ParseStyleMapError::Key("=".into())));
+    assert_eq!(
+        map.load("note=36:error=1=1").unwrap_err().to_string(),
+        "Invalid SGR parameter: 1=1 for key: error"
+    );
+    assert_eq!(map.get("note"), None);
+}
@@ -0,0 +1,75 @@
+use std::error::Error;
+
+use easy_sgr::{CleanKind, Color::*, EasySGR, ParseThemeError, Style::*, Theme};
+
+#[test]
+fn default() {
+    let theme = Theme::default();
+    assert!(theme.iter().map(|(role, _)| role).eq({
+        let mut roles = Theme::ROLES;
+        roles.sort_unstable();
+        roles
+    }));
+    assert_eq!(
+        theme.get("error"),
+        Some(&Bold.color(RedFg).clean(CleanKind::Reverse))
+    );
+    assert_eq!(
+        "\x1b[33;1mwarned\x1b[39;22m",
+        theme.paint("warning", "warned").to_string()
+    );
+    assert_eq!(Theme::new().iter().count(), 0);
+}
+
+#[test]
+fn load() {
+    let mut theme = Theme::default();
+    theme
+        .load(
+            "
+            # comments & blank lines are skipped
+            error = 1;38;5;196
+            custom=4:3
+            hint =
+            ",
+        )
+        .unwrap();
+    assert_eq!(
+        theme.get("error"),
+        Some(&Bold.color(ByteFg(196)).clean(CleanKind::Reverse))
+    );
+    assert_eq!(
+        "\x1b[4:3mcurly\x1b[24m",
+        theme.paint("custom", "curly").to_string()
+    );
+    assert_eq!("plain", theme.paint("hint", "plain").to_string());
+    assert_eq!("plain", theme.paint("missing", "plain").to_string());
+
+    let theme: Theme = "b = 1\na = 31;4".parse().unwrap();
+    assert_eq!("a = 31;4\nb = 1\n", theme.to_string());
+    assert_eq!(Ok(theme.clone()), theme.to_string().parse());
+}
+
+#[test]
+fn edit() {
+    let mut theme = Theme::new();
+    assert_eq!(theme.set("key", BlueFg), None);
+    assert_eq!(theme.set("key", GreenFg), Some(BlueFg.into()));
+    assert_eq!(theme.remove("key"), Some(GreenFg.into()));
+    assert_eq!(theme.get("key"), None);
+}
+
+#[test]
+fn errors() {
+    let mut theme = Theme::default();
+    assert_eq!(
+        theme.load("error = 1\nno separator"),
+        Err(ParseThemeError::Separator(2))
+    );
+    assert_eq!(theme.load(" = 1"), Err(ParseThemeError::Role(1)));
+    let err = theme.load("\n\nerror = 1;red").unwrap_err();
+    assert_eq!(err.to_string(), "Invalid SGR parameter: red on line 3");
+    assert!(err.source().is_some());
+    // nothing is loaded when an error is found
+    assert_eq!(theme, Theme::default());
+}
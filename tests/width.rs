@@ -0,0 +1,48 @@
+use easy_sgr::{display_width, CleanKind, Color::*, EasySGR, Style::*};
+
+#[test]
+fn ascii() {
+    for (test, width) in [
+        ("", 0),
+        ("test", 4),
+        ("test\n\t\x07", 4),
+        ("\x1b[31;1mtest\x1b[0m", 4),
+        ("\x1b[38;5;208mte\x1b[4:3mst\x1b[m", 4),
+        ("\x1b[2Ktest", 7),
+    ] {
+        assert_eq!(display_width(test), width, "{test:?}");
+    }
+}
+
+#[test]
+fn unicode() {
+    for (test, width) in [
+        ("日本語", 6),
+        ("ｆｕｌｌ", 8),
+        ("한국어", 6),
+        ("e\u{301}", 1),
+        ("\u{1160}", 0),
+        ("\u{200B}", 0),
+        ("☀", 1),
+        ("☀\u{FE0F}", 2),
+        ("😀", 2),
+        ("👍\u{1F3FD}", 2),
+        ("👨\u{200D}👩\u{200D}👧", 2),
+        ("a\u{200D}b", 2),
+        ("🇨🇦", 2),
+        ("\u{20000}", 2),
+    ] {
+        assert_eq!(display_width(test), width, "{test:?}");
+    }
+}
+
+#[test]
+fn sgr_string() {
+    let sgr = "日本 test"
+        .color(RedFg)
+        .style(Bold)
+        .clean(CleanKind::Reverse);
+    assert_eq!(sgr.display_width(), 9);
+    assert_eq!(display_width(&sgr.to_string()), 9);
+    assert_eq!(Italic.to_sgr().display_width(), 0);
+}
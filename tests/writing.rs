@@ -1,6 +1,9 @@
 use std::error::Error;
 
-use easy_sgr::{Color, EasySGR, SGRWriter, Style};
+use easy_sgr::{
+    CapableBuilder, CapableWriter, Color, ColorEncoding, DiscreteSGR, EasySGR, InlineBuilder,
+    Param, SGRState, SGRWriter, Style,
+};
 
 #[test]
 fn sgr_writer() -> Result<(), Box<dyn Error>> {
@@ -27,8 +30,161 @@ fn sgr_builder() -> Result<(), Box<dyn Error>> {
     builder
         .chain_code(3)
         .chain_codes(&[4, 5])
+        .chain_sub(4, 3)
+        .chain_param(Param::Byte(38, 208))
         .write_to(&mut w)?;
 
-    assert_eq!("\x1b[0;1;2;3;4;5m", w.internal());
+    assert_eq!("\x1b[0;1;2;3;4;5;4:3;38;5;208m", w.internal());
+    Ok(())
+}
+
+#[test]
+fn disabled() -> Result<(), Box<dyn Error>> {
+    let mut w = SGRWriter::from(String::new());
+    w.enabled = false;
+    w.write_inner("test")?;
+    w.sgr(&Color::RedFg.style(Style::Italic))?;
+    w.inline_sgr(&Style::Bold)?;
+    w.place_sgr(&"text".style(Style::Bold))?;
+    w.clean_sgr(&"text".style(Style::Bold))?;
+    w.partial_sgr(&Style::Bold)?;
+
+    assert_eq!("test", w.internal());
+    Ok(())
+}
+
+#[test]
+fn color_encoding() -> Result<(), Box<dyn Error>> {
+    let mut w = SGRWriter::from(String::new());
+    w.color_encoding = ColorEncoding::Colon;
+    w.sgr(&Color::RgbFg(1, 2, 3).color(Color::ByteBg(4)))?;
+    w.inline_sgr(&Color::RgbUl(5, 6, 7))?;
+    w.partial_sgr(&Style::CurlyUnderline.color(Color::ByteUl(8)))?;
+
+    assert_eq!(
+        "\x1b[38:2::1:2:3;48:5:4m\x1b[58:2::5:6:7m58:5:8;4:3",
+        w.internal()
+    );
+    assert_eq!("38;2;1;2;3", Param::Rgb(38, 1, 2, 3).to_string());
+    assert_eq!("4:3", Param::Sub(4, 3).encode(ColorEncoding::Colon));
+    Ok(())
+}
+
+#[test]
+fn tracked_state() -> Result<(), Box<dyn Error>> {
+    let mut w = SGRWriter::from(String::new());
+    assert_eq!(None, w.state());
+    w.reset_if_dirty()?;
+    assert_eq!("\x1b[0m", w.writer.0);
+
+    let mut w = SGRWriter::from(String::new());
+    w.track_state(true);
+    w.reset_if_dirty()?;
+    w.place_sgr(&Style::Bold.color(Color::RedFg))?;
+    w.inline_sgr(&Style::Bold)?;
+    w.sgr(&Color::BlueFg.style(Style::Bold).custom(99))?;
+    w.write_inner("text\x1b[3m")?;
+    assert!(w.state().is_some_and(|state| state.bold && state.italic));
+    w.inline_sgr(&Style::Italic)?;
+    w.partial_sgr(&Style::Dim)?;
+    assert!(w.state().is_some_and(|state| state.dim));
+    w.reset_if_dirty()?;
+    w.reset_if_dirty()?;
+    assert_eq!(Some(&SGRState::default()), w.state());
+    assert_eq!("\x1b[31;1m\x1b[34;99mtext\x1b[3m2\x1b[0m", w.internal());
+    Ok(())
+}
+
+#[test]
+fn split_sequences() -> Result<(), Box<dyn Error>> {
+    let mut w = SGRWriter::from(String::new());
+    w.track_state(true);
+    for chunk in ["a\x1b", "[1", ";3", "mb\x1b[", "2", "2mc\x1b[9"] {
+        w.write_inner(chunk)?;
+    }
+    assert!(w.state().is_some_and(|state| !state.bold && state.italic));
+    // the unfinished sequence turns out not to be SGR
+    w.write_inner("x\x1b[1m")?;
+    assert!(w
+        .state()
+        .is_some_and(|state| state.bold && !state.strikethrough));
+    w.inline_sgr(&Style::Italic)?;
+    assert_eq!("a\x1b[1;3mb\x1b[22mc\x1b[9x\x1b[1m", w.internal());
+
+    let text = "\x1b[31;1mte\x1b[4:3mxt\x1b[39;58;5;208m";
+    let mut whole = SGRWriter::from(String::new());
+    whole.track_state(true);
+    whole.write_inner(text)?;
+    for size in 1..text.len() {
+        let mut w = SGRWriter::from(String::new());
+        w.track_state(true);
+        for i in (0..text.len()).step_by(size) {
+            w.write_inner(&text[i..text.len().min(i + size)])?;
+        }
+        assert_eq!(whole.state(), w.state());
+    }
+    Ok(())
+}
+
+#[test]
+fn single_write() -> Result<(), Box<dyn Error>> {
+    #[derive(Default)]
+    struct Counter(Vec<String>);
+    impl CapableWriter for Counter {
+        type Writer = Vec<String>;
+        type Error = std::fmt::Error;
+        fn write(&mut self, s: &str) -> Result<(), Self::Error> {
+            self.0.push(s.to_owned());
+            Ok(())
+        }
+        fn get_writer(self) -> Self::Writer {
+            self.0
+        }
+    }
+    let mut w = SGRWriter::from(Counter::default());
+    w.sgr(&Color::RgbFg(255, 255, 255).style(Style::Bold))?;
+    w.partial_sgr(&Color::ByteBg(208))?;
+    assert_eq!(w.internal(), ["\x1b[38;2;255;255;255;1m", "48;5;208"]);
+
+    // too long to be written at once
+    let mut w = SGRWriter::from(String::new());
+    let mut builder = w.builder();
+    (0..20).for_each(|_| builder.write_param(Param::Rgb(38, 255, 255, 255)));
+    builder.write_to(&mut w)?;
+    let codes = vec!["38;2;255;255;255"; 20].join(";");
+    assert_eq!(format!("\x1b[{codes}m"), w.internal());
+    Ok(())
+}
+
+#[test]
+fn inline_builder() -> Result<(), Box<dyn Error>> {
+    let mut builder = InlineBuilder::default();
+    Color::RgbFg(1, 2, 3).write(&mut builder);
+    Style::Bold.color(Color::BlueBg).place_all(&mut builder);
+    builder.write_sub(4, 3);
+    assert_eq!(
+        builder.params(),
+        [
+            Param::Rgb(38, 1, 2, 3),
+            Param::Code(44),
+            Param::Code(1),
+            Param::Sub(4, 3)
+        ]
+    );
+    let mut w = SGRWriter::from(String::new());
+    builder.write_to(&mut w)?;
+    assert_eq!("\x1b[38;2;1;2;3;44;1;4:3m", w.internal());
+
+    (0..40).for_each(|code| builder.write_code(code));
+    assert!(builder.is_full());
+    assert_eq!(InlineBuilder::CAPACITY, builder.params().len());
+
+    // too many custom codes to write inline
+    let sgr = (0..20).fold("text".to_sgr(), |sgr, code| sgr.custom(code));
+    let mut w = SGRWriter::from(String::new());
+    w.place_sgr(&sgr)?;
+    w.sgr(&sgr)?;
+    let codes = (0..20).map(|code: u8| code.to_string()).collect::<Vec<_>>();
+    assert_eq!(format!("\x1b[{0}m\x1b[{0}m", codes.join(";")), w.internal());
     Ok(())
 }